@@ -0,0 +1,122 @@
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+/// The answer to one part of a puzzle.
+///
+/// Numbers compare by value, so `Signed(5)` and `Unsigned(5)` are equal, while text only
+/// equals text: `Text("5")` is not `Unsigned(5)`.
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Signed(n) => write!(f, "{}", n),
+            Self::Unsigned(n) => write!(f, "{}", n),
+            Self::Text(text) => write!(f, "{}", text),
+            Self::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Signed(a), Self::Unsigned(b)) | (Self::Unsigned(b), Self::Signed(a)) => {
+                u64::try_from(*a).is_ok_and(|a| a == *b)
+            }
+            (Self::Signed(a), Self::Signed(b)) => a == b,
+            (Self::Unsigned(a), Self::Unsigned(b)) => a == b,
+            (Self::Text(a), Self::Text(b)) => a == b,
+            (Self::Unsolved, Self::Unsolved) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Self::Unsolved)
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Reads back an answer written by [`Display`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(if s == "unsolved" {
+            Self::Unsolved
+        } else if let Ok(n) = u64::from_str_radix(s, 10) {
+            Self::Unsigned(n)
+        } else if let Ok(n) = i64::from_str_radix(s, 10) {
+            Self::Signed(n)
+        } else {
+            Self::Text(s.to_owned())
+        })
+    }
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident, $target:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Unsigned, u64, u8, u16, u32, u64, usize);
+impl_from_int!(Signed, i64, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(Answer::from(12usize).to_string(), "12");
+        assert_eq!(Answer::from("abcdffaa").to_string(), "abcdffaa");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for answer in [
+            Answer::Signed(-40),
+            Answer::Unsigned(1227775554),
+            Answer::Text(String::from("ghjaabcc")),
+            Answer::Unsolved,
+        ] {
+            assert_eq!(answer.to_string().parse::<Answer>().unwrap(), answer);
+        }
+    }
+
+    #[test]
+    fn test_signed_matches_unsigned() {
+        assert_eq!(Answer::Signed(5), Answer::Unsigned(5));
+        assert_ne!(Answer::Signed(-5), Answer::Unsigned(5));
+        assert_ne!(Answer::Text(String::from("5")), Answer::Unsigned(5));
+    }
+}
@@ -0,0 +1,150 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::Part;
+
+/// How much effort to spend timing each stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    /// Runs thrown away before timing starts.
+    pub warmup: usize,
+    /// The most timed runs to take.
+    pub iterations: usize,
+    /// Stop taking timed runs once this much time has been spent, keeping at least one.
+    pub max_time: Duration,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 100,
+            max_time: Duration::from_secs(10),
+        }
+    }
+}
+
+/// A step of solving a day that can be timed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// Summary of the timed runs of a stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Panics if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let runs = sorted.len();
+
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / runs as f64;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+
+        Self {
+            runs,
+            mean: Duration::from_secs_f64(mean),
+            median,
+            min: sorted[0],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Times `f` repeatedly within the budget.
+pub fn measure<T>(budget: &Budget, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..budget.warmup {
+        black_box(f());
+    }
+
+    let mut samples = Vec::with_capacity(budget.iterations);
+    let start = Instant::now();
+    while samples.len() < budget.iterations.max(1) {
+        let run = Instant::now();
+        black_box(f());
+        samples.push(run.elapsed());
+
+        if start.elapsed() >= budget.max_time {
+            break;
+        }
+    }
+
+    Stats::from_samples(&samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        // population standard deviation of 1, 2, 3 and 4
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn test_measure_budget() {
+        let mut calls = 0;
+        let budget = Budget {
+            warmup: 2,
+            iterations: 5,
+            max_time: Duration::from_secs(60),
+        };
+        let stats = measure(&budget, || calls += 1);
+
+        assert_eq!(stats.runs, 5);
+        assert_eq!(calls, 7);
+    }
+
+    #[test]
+    fn test_measure_time_limit() {
+        let budget = Budget {
+            warmup: 0,
+            iterations: 1000,
+            max_time: Duration::ZERO,
+        };
+
+        assert_eq!(measure(&budget, || ()).runs, 1);
+    }
+}
@@ -0,0 +1,321 @@
+//! Differential testing, for days with a slow but obviously right way to get
+//! an answer alongside a fast one.
+//!
+//! Both are run on many generated cases, and the first case they disagree on
+//! is shrunk to the smallest input that still shows the disagreement.
+
+use std::{
+    env,
+    fmt::{Debug, Display},
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::Rng;
+
+/// How many cases to run, unless [`Differential::cases`] says otherwise.
+pub const CASES_VAR: &str = "AOC_DIFF_CASES";
+const DEFAULT_CASES: usize = 1000;
+
+/// Smaller versions of a value, for narrowing down a failing input.
+pub trait Shrink: Sized {
+    /// Candidates to try in place of `self`, most promising first. None of
+    /// them may be `self`, or shrinking would never end.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! shrink_unsigned {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let mut smaller = vec![0, self / 2, self.saturating_sub(1)];
+                smaller.dedup();
+                smaller.retain(|n| n != self);
+                smaller
+            }
+        }
+    )*};
+}
+
+shrink_unsigned!(u8, u16, u32, u64, usize);
+
+impl Shrink for i64 {
+    fn shrink(&self) -> Vec<Self> {
+        let mut smaller = vec![0, self / 2, self - self.signum()];
+        if *self < 0 {
+            smaller.push(-self);
+        }
+        smaller.dedup();
+        smaller.retain(|n| n != self);
+        smaller
+    }
+}
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        if *self { vec![false] } else { Vec::new() }
+    }
+}
+
+/// Text is treated as a name, kept whole.
+impl Shrink for String {
+    fn shrink(&self) -> Vec<Self> {
+        Vec::new()
+    }
+}
+
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut smaller = Vec::new();
+        // NOTE: dropping big chunks first gets small quickly, single items then tidy up
+        let mut chunk = self.len() / 2;
+        while chunk > 0 {
+            for start in (0..self.len()).step_by(chunk) {
+                let mut without = self.clone();
+                without.drain(start..(start + chunk).min(self.len()));
+                smaller.push(without);
+            }
+            chunk /= 2;
+        }
+        for (i, item) in self.iter().enumerate() {
+            for item in item.shrink() {
+                let mut with = self.clone();
+                with[i] = item;
+                smaller.push(with);
+            }
+        }
+        smaller
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let firsts = self.0.shrink().into_iter().map(|a| (a, self.1.clone()));
+        let seconds = self.1.shrink().into_iter().map(|b| (self.0.clone(), b));
+        firsts.chain(seconds).collect()
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone, C: Shrink + Clone> Shrink for (A, B, C) {
+    fn shrink(&self) -> Vec<Self> {
+        let firsts = self
+            .0
+            .shrink()
+            .into_iter()
+            .map(|a| (a, self.1.clone(), self.2.clone()));
+        let seconds = self
+            .1
+            .shrink()
+            .into_iter()
+            .map(|b| (self.0.clone(), b, self.2.clone()));
+        let thirds = self
+            .2
+            .shrink()
+            .into_iter()
+            .map(|c| (self.0.clone(), self.1.clone(), c));
+        firsts.chain(seconds).chain(thirds).collect()
+    }
+}
+
+/// What an implementation gave for an input, with a panic kept as its message.
+pub type Outcome<O> = Result<O, String>;
+
+/// The smallest input found on which the two implementations disagree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch<I, O> {
+    /// The seed of the case that first disagreed.
+    pub seed: u64,
+    pub input: I,
+    pub reference: Outcome<O>,
+    pub candidate: Outcome<O>,
+    /// How many times the original input was made smaller.
+    pub shrinks: usize,
+}
+
+impl<I: Debug, O: Debug> Display for Mismatch<I, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let show = |outcome: &Outcome<O>| match outcome {
+            Ok(answer) => format!("{:?}", answer),
+            Err(message) => format!("panicked: {}", message),
+        };
+        write!(
+            f,
+            "the implementations disagree on case seed {}, shrunk {} times to\n  input:     {:?}\n  reference: {}\n  candidate: {}",
+            self.seed,
+            self.shrinks,
+            self.input,
+            show(&self.reference),
+            show(&self.candidate)
+        )
+    }
+}
+
+impl<I: Debug, O: Debug> std::error::Error for Mismatch<I, O> {}
+
+type Implementation<'a, I, O> = Box<dyn Fn(&I) -> O + 'a>;
+
+/// A reference implementation and a candidate that should always agree with
+/// it.
+pub struct Differential<'a, I, O> {
+    reference: Implementation<'a, I, O>,
+    candidate: Implementation<'a, I, O>,
+    valid: Box<dyn Fn(&I) -> bool + 'a>,
+    cases: Option<usize>,
+    seed: u64,
+}
+
+/// Runs `f`, turning a panic into its message.
+pub fn catch<I: ?Sized, O>(f: &dyn Fn(&I) -> O, input: &I) -> Outcome<O> {
+    panic::catch_unwind(AssertUnwindSafe(|| f(input))).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| String::from("a panic without a message"))
+    })
+}
+
+impl<'a, I: Shrink + Clone, O: PartialEq> Differential<'a, I, O> {
+    pub fn new(reference: impl Fn(&I) -> O + 'a, candidate: impl Fn(&I) -> O + 'a) -> Self {
+        Self {
+            reference: Box::new(reference),
+            candidate: Box::new(candidate),
+            valid: Box::new(|_| true),
+            cases: None,
+            seed: 0,
+        }
+    }
+
+    /// Only inputs that pass `valid` are tried while shrinking, for when a
+    /// smaller value can break what the implementations rely on.
+    pub fn valid(mut self, valid: impl Fn(&I) -> bool + 'a) -> Self {
+        self.valid = Box::new(valid);
+        self
+    }
+
+    /// How many cases to run, overriding `AOC_DIFF_CASES`.
+    pub fn cases(mut self, cases: usize) -> Self {
+        self.cases = Some(cases);
+        self
+    }
+
+    /// The seed of the first case, the rest count up from it.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    fn case_count(&self) -> usize {
+        self.cases.unwrap_or_else(|| {
+            env::var(CASES_VAR)
+                .ok()
+                .and_then(|cases| usize::from_str_radix(&cases, 10).ok())
+                .unwrap_or(DEFAULT_CASES)
+        })
+    }
+
+    /// Both outcomes for `input`, if they differ.
+    fn disagreement(&self, input: &I) -> Option<(Outcome<O>, Outcome<O>)> {
+        let reference = catch(&self.reference, input);
+        let candidate = catch(&self.candidate, input);
+        (reference != candidate).then_some((reference, candidate))
+    }
+
+    /// Runs every case, shrinking the first disagreement.
+    ///
+    /// Generated inputs should pass the [`Differential::valid`] check.
+    pub fn run(&self, generate: impl Fn(&mut Rng) -> I) -> Result<(), Mismatch<I, O>> {
+        for seed in self.seed..self.seed + self.case_count() as u64 {
+            let input = generate(&mut Rng::new(seed));
+            if let Some((reference, candidate)) = self.disagreement(&input) {
+                return Err(self.shrink(seed, input, reference, candidate));
+            }
+        }
+        Ok(())
+    }
+
+    /// Greedily swaps the input for the first smaller one that still
+    /// disagrees, until none do.
+    fn shrink(
+        &self,
+        seed: u64,
+        mut input: I,
+        mut reference: Outcome<O>,
+        mut candidate: Outcome<O>,
+    ) -> Mismatch<I, O> {
+        let mut shrinks = 0;
+        'shrinking: loop {
+            for smaller in input.shrink() {
+                if !(self.valid)(&smaller) {
+                    continue;
+                }
+                if let Some(outcomes) = self.disagreement(&smaller) {
+                    input = smaller;
+                    (reference, candidate) = outcomes;
+                    shrinks += 1;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+
+        Mismatch {
+            seed,
+            input,
+            reference,
+            candidate,
+            shrinks,
+        }
+    }
+}
+
+impl<I: Shrink + Clone + Debug, O: PartialEq + Debug> Differential<'_, I, O> {
+    /// Like [`Differential::run`], but panics with the shrunk input, for tests.
+    pub fn check(&self, generate: impl Fn(&mut Rng) -> I) {
+        if let Err(mismatch) = self.run(generate) {
+            panic!("{}", mismatch);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agree() {
+        Differential::new(|n: &u64| n * 2, |n: &u64| n + n)
+            .cases(100)
+            .check(|rng| rng.below(1000));
+    }
+
+    #[test]
+    fn test_shrink_to_smallest() {
+        // wrong once the list holds a 7 or more
+        let mismatch = Differential::new(
+            |items: &Vec<u8>| items.iter().map(|n| *n as u32).sum::<u32>(),
+            |items: &Vec<u8>| items.iter().map(|n| (*n).min(6) as u32).sum::<u32>(),
+        )
+        .cases(100)
+        .run(|rng| (0..20).map(|_| rng.below(10) as u8).collect())
+        .unwrap_err();
+
+        assert_eq!(mismatch.input, vec![7]);
+        assert_eq!((mismatch.reference, mismatch.candidate), (Ok(7), Ok(6)));
+    }
+
+    #[test]
+    fn test_panics_disagree() {
+        let mismatch = Differential::new(
+            |(a, b): &(u32, u32)| a.checked_div(*b),
+            |(a, b): &(u32, u32)| Some(a / b),
+        )
+        .valid(|(a, _)| *a > 0)
+        .cases(100)
+        .run(|rng| (rng.below(100) as u32, rng.below(3) as u32))
+        .unwrap_err();
+
+        assert_eq!(mismatch.input, (1, 0));
+        assert_eq!(mismatch.reference, Ok(None));
+        assert!(mismatch.candidate.unwrap_err().contains("divide by zero"));
+    }
+}
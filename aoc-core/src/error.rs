@@ -0,0 +1,269 @@
+use std::{any::type_name, fmt::Display, ops::Range, str::FromStr};
+
+/// Where in the input an [`AocError`] happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// Counted from 1.
+    pub line: usize,
+    /// Counted in characters from 1.
+    pub column: usize,
+    /// The whole line, for showing the error in context.
+    pub source: String,
+}
+
+/// Input that didn't match what a parser expected.
+///
+/// Parsers work on slices of the input, so an error made from one only
+/// remembers where that slice sits in memory. [`AocError::locate`] later turns
+/// that into a line and column using the whole input, which saves threading
+/// the input through every helper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    /// The offending text, empty when something was missing.
+    pub text: String,
+    /// What should have been there, such as "a number".
+    pub expected: String,
+    /// Filled in by [`AocError::locate`].
+    pub location: Option<Location>,
+    span: Option<Range<usize>>,
+}
+
+impl AocError {
+    /// An error for `found`, which should be a slice of the input.
+    pub fn new(found: &str, expected: impl Into<String>) -> Self {
+        Self::expected(expected).at(found)
+    }
+
+    /// An error for something missing straight after `text`.
+    pub fn after(text: &str, expected: impl Into<String>) -> Self {
+        Self::new(&text[text.len()..], expected)
+    }
+
+    /// An error that doesn't yet know what it was found in, for conversions
+    /// like `TryFrom<char>` that never see the input. Give it the text with
+    /// [`AocError::at`].
+    pub fn expected(expected: impl Into<String>) -> Self {
+        Self {
+            text: String::new(),
+            expected: expected.into(),
+            location: None,
+            span: None,
+        }
+    }
+
+    /// Points the error at `found`, a slice of the input.
+    pub fn at(mut self, found: &str) -> Self {
+        // NOTE: only the address is kept, it's never read through
+        let start = found.as_ptr() as usize;
+        self.text = found.to_owned();
+        self.span = Some(start..start + found.len());
+        self
+    }
+
+    /// Whether the error has been pointed at any text yet.
+    pub fn is_anchored(&self) -> bool {
+        self.span.is_some()
+    }
+
+    /// Works out the line and column of the error within `input`.
+    ///
+    /// Errors from text that isn't part of `input` are left as they are.
+    pub fn locate(mut self, input: &str) -> Self {
+        let base = input.as_ptr() as usize;
+        let Some(span) = &self.span else {
+            return self;
+        };
+        if span.start < base || span.end > base + input.len() {
+            return self;
+        }
+
+        let offset = span.start - base;
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        self.location = Some(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            source: input[line_start..line_end].to_owned(),
+        });
+        self
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(location) = &self.location {
+            write!(f, "line {}, column {}: ", location.line, location.column)?;
+        }
+        write!(f, "expected {}", self.expected)?;
+        // NOTE: an error that was never pointed at anything is about the whole input
+        match self.text.as_str() {
+            "" if self.span.is_none() => {}
+            "" => write!(f, ", found nothing")?,
+            text => write!(f, ", found {:?}", text)?,
+        }
+
+        if let Some(location) = &self.location {
+            let number = location.line.to_string();
+            let pad = " ".repeat(number.len());
+            // NOTE: a caret for every character, but only up to the end of the line
+            let width = self
+                .text
+                .lines()
+                .next()
+                .map_or(1, |first| first.chars().count().max(1));
+            write!(
+                f,
+                "\n{} |\n{} | {}\n{} | {}{}",
+                pad,
+                number,
+                location.source,
+                pad,
+                " ".repeat(location.column - 1),
+                "^".repeat(width)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for AocError {}
+
+/// Parses `text`, describing it as `expected` if it doesn't parse.
+pub fn parse_as<T: FromStr>(text: &str, expected: &str) -> Result<T, AocError> {
+    text.parse().map_err(|_| AocError::new(text, expected))
+}
+
+/// Parses `text` as a number of type `T`.
+pub fn number<T: FromStr>(text: &str) -> Result<T, AocError> {
+    let name = type_name::<T>();
+    text.parse()
+        .map_err(|_| AocError::new(text, format!("a number that fits in {}", name)))
+}
+
+/// Splits `text` around the first `sep`, which must be there.
+pub fn split_once<'a>(text: &'a str, sep: &str) -> Result<(&'a str, &'a str), AocError> {
+    text.split_once(sep)
+        .ok_or_else(|| AocError::after(text, format!("{:?}", sep)))
+}
+
+/// Splits `text` where `sep` starts, without taking it off, or failing that
+/// where its first character does, so a near miss is reported by [`prefix`]
+/// at the word that differs.
+pub fn until<'a>(text: &'a str, sep: &str) -> (&'a str, &'a str) {
+    let at = text
+        .find(sep)
+        .or_else(|| text.find(sep.chars().next()?))
+        .unwrap_or(text.len());
+    text.split_at(at)
+}
+
+/// Strips `prefix` from the start of `text`, which must begin with it.
+pub fn prefix<'a>(text: &'a str, prefix: &str) -> Result<&'a str, AocError> {
+    text.strip_prefix(prefix).ok_or_else(|| {
+        // NOTE: point at the word from where the text stops matching
+        let start = text
+            .char_indices()
+            .zip(prefix.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(text.len(), |((i, _), _)| i);
+        let end = text[start..]
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| *c == ' ')
+            .map_or(text.len(), |(i, _)| start + i);
+        AocError::new(&text[start..end], format!("{:?}", prefix))
+    })
+}
+
+/// Checks nothing is left of `text`.
+pub fn end(text: &str) -> Result<(), AocError> {
+    match text {
+        "" => Ok(()),
+        rest => Err(AocError::new(rest, "the end of the line")),
+    }
+}
+
+/// A value read from one piece of a line, as `#[derive(AocParse)]` does for
+/// each field in its pattern.
+pub trait Field: Sized {
+    fn field(text: &str) -> Result<Self, AocError>;
+}
+
+macro_rules! field_number {
+    ($($t:ty),*) => {$(
+        impl Field for $t {
+            fn field(text: &str) -> Result<Self, AocError> {
+                number(text)
+            }
+        }
+    )*};
+}
+
+field_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl Field for String {
+    fn field(text: &str) -> Result<Self, AocError> {
+        match text {
+            "" => Err(AocError::new(text, "some text")),
+            text => Ok(text.to_owned()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let input = "1x2x3\n4x5xy\n";
+        let bad = &input[10..11];
+        let err = AocError::new(bad, "a number").locate(input);
+
+        let location = err.location.as_ref().unwrap();
+        assert_eq!((location.line, location.column), (2, 5));
+        assert_eq!(location.source, "4x5xy");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 5: expected a number, found \"y\"\n  |\n2 | 4x5xy\n  |     ^"
+        );
+    }
+
+    #[test]
+    fn test_outside_input() {
+        let other = String::from("y");
+        let err = AocError::new(&other, "a number").locate("1x2x3\n");
+
+        assert_eq!(err.location, None);
+        assert_eq!(err.to_string(), "expected a number, found \"y\"");
+    }
+
+    #[test]
+    fn test_helpers() {
+        let input = "12 apples\n-3 pears";
+        let line = input.lines().nth(1).unwrap();
+
+        assert_eq!(number::<i32>(&line[..2]), Ok(-3));
+        let err = number::<u32>(&line[..2]).unwrap_err().locate(input);
+        assert_eq!(err.expected, "a number that fits in u32");
+        assert_eq!(err.location.unwrap().column, 1);
+
+        assert_eq!(split_once(line, " "), Ok(("-3", "pears")));
+        let err = split_once(line, ": ").unwrap_err().locate(input);
+        assert_eq!(err.text, "");
+        assert_eq!(err.location.unwrap().column, 9);
+
+        assert_eq!(until(line, " pe"), ("-3", " pears"));
+        assert_eq!(until(line, " apples"), ("-3", " pears"));
+        assert_eq!(until(line, "!"), ("-3 pears", ""));
+        assert_eq!(prefix(line, "-3 "), Ok("pears"));
+        let err = prefix(line, "-3 apples").unwrap_err().locate(input);
+        assert_eq!(err.text, "pears");
+        assert_eq!(err.location.unwrap().column, 4);
+        assert_eq!(prefix("-3", "-3 ").unwrap_err().text, "");
+        assert_eq!(end(&line[2..]).unwrap_err().text, " pears");
+    }
+}
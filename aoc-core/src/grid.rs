@@ -0,0 +1,159 @@
+use std::fmt::Display;
+
+use crate::AocError;
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from the non-empty lines of `input`, mapping each character to a cell.
+    ///
+    /// Panics if the rows are not all the same width.
+    pub fn parse(input: &str, cell: impl FnMut(char) -> T) -> Self {
+        Self::from_rows(
+            crate::lines(input)
+                .map(|line| line.chars().collect::<Vec<char>>())
+                .collect::<Vec<_>>(),
+            cell,
+        )
+    }
+
+    /// Like [`Grid::parse`], but for cells that might not convert, and for
+    /// input that might not be rectangular.
+    pub fn try_parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, AocError>,
+    ) -> Result<Self, AocError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in crate::lines(input) {
+            let row_width = line.chars().count();
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(AocError::new(
+                    line,
+                    format!("a row {} wide like the first", width.unwrap_or_default()),
+                ));
+            }
+            for (i, c) in line.char_indices() {
+                let found = &line[i..i + c.len_utf8()];
+                cells.push(cell(c).map_err(|err| match err.is_anchored() {
+                    true => err,
+                    false => err.at(found),
+                })?);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    fn from_rows(rows: Vec<Vec<char>>, mut cell: impl FnMut(char) -> T) -> Self {
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for row in rows {
+            assert_eq!(row.len(), width, "grid rows must all be the same width");
+            cells.extend(row.into_iter().map(&mut cell));
+        }
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Gets the cell at `(x + i, y + j)`, if it is inside the grid.
+    pub fn get_offset(&self, x: usize, y: usize, i: isize, j: isize) -> Option<&T> {
+        self.get(x.checked_add_signed(i)?, y.checked_add_signed(j)?)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates over every cell along with its `(x, y)` position.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i % self.width, i / self.width), cell))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut((usize, usize), &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(pos, cell)| f(pos, cell)).collect(),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#.\n.#\n", |c| c == '#');
+
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(0, 0), Some(&true));
+        assert_eq!(grid.get(1, 0), Some(&false));
+        assert_eq!(grid.get(2, 0), None);
+    }
+
+    #[test]
+    fn test_get_offset() {
+        let grid = Grid::parse("ab\ncd\n", |c| c);
+
+        assert_eq!(grid.get_offset(0, 0, 1, 1), Some(&'d'));
+        assert_eq!(grid.get_offset(0, 0, -1, 0), None);
+    }
+}
@@ -0,0 +1,314 @@
+use std::{
+    borrow::Cow,
+    fmt::{Debug, Display},
+    fs,
+    io::{self, Read},
+    ops::Deref,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{
+    AocError, Grid,
+    error::{number, split_once},
+};
+
+/// Directory holding each user's inputs, laid out as `<year>/<day>.txt`.
+pub const INPUTS_DIR: &str = "inputs";
+
+/// Passing this as the input path reads from stdin instead.
+pub const STDIN: &str = "-";
+
+#[derive(Debug)]
+pub enum InputError {
+    /// An explicitly requested input couldn't be read.
+    Read { source: String, err: io::Error },
+    /// None of the default locations had an input.
+    NotFound {
+        year: u16,
+        day: u8,
+        tried: Vec<PathBuf>,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read { source, err } => write!(f, "unable to read {}: {}", source, err),
+            Self::NotFound { year, day, tried } => {
+                write!(f, "no input for {} day {}, tried ", year, day)?;
+                for (i, path) in tried.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", path.display())?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Where a day's input is kept under `root`, and where `aoc fetch` saves it.
+pub fn input_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(INPUTS_DIR)
+        .join(year.to_string())
+        .join(format!("{}.txt", day))
+}
+
+/// The places an input is looked for when no path is given, in order.
+pub fn candidates(root: &Path, year: u16, day: u8) -> Vec<PathBuf> {
+    vec![
+        input_path(root, year, day),
+        // NOTE: where inputs lived before the runner, next to each day's crate
+        root.join(format!("y{}/d{}/input.txt", year, day)),
+        root.join("input.txt"),
+    ]
+}
+
+/// Loads the input for a day.
+///
+/// An explicit path wins, with `-` meaning stdin. Otherwise the first of the
+/// [`candidates`] that exists is used.
+pub fn load_input(year: u16, day: u8, explicit: Option<&str>) -> Result<String, InputError> {
+    load_input_from(Path::new(""), year, day, explicit)
+}
+
+fn load_input_from(
+    root: &Path,
+    year: u16,
+    day: u8,
+    explicit: Option<&str>,
+) -> Result<String, InputError> {
+    match explicit {
+        Some(STDIN) => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| InputError::Read {
+                    source: String::from("stdin"),
+                    err,
+                })?;
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path).map_err(|err| InputError::Read {
+            source: path.to_owned(),
+            err,
+        }),
+        None => {
+            let tried = candidates(root, year, day);
+            tried
+                .iter()
+                .find_map(|path| fs::read_to_string(path).ok())
+                .ok_or(InputError::NotFound { year, day, tried })
+        }
+    }
+}
+
+/// Splits the input into lines, skipping any that are empty.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.split('\n').filter(|line| !line.is_empty())
+}
+
+/// Puzzle input with `\r\n` line endings and trailing blank lines normalised away.
+///
+/// Derefs to the normalised text, so anything taking a `&str` still works.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input<'a> {
+    text: Cow<'a, str>,
+}
+
+/// Cuts any trailing lines that are empty or only whitespace, leaving the rest untouched.
+fn trimmed_len(text: &str) -> usize {
+    let mut end = text.len();
+    loop {
+        let rest = text[..end].trim_end_matches('\n');
+        let last_start = rest.rfind('\n').map_or(0, |i| i + 1);
+        if !rest.is_empty() && rest[last_start..].trim().is_empty() {
+            end = last_start;
+        } else {
+            return rest.len();
+        }
+    }
+}
+
+impl<'a> Input<'a> {
+    pub fn new(raw: &'a str) -> Self {
+        let text = if raw.contains('\r') {
+            let mut text = raw.replace("\r\n", "\n");
+            text.truncate(trimmed_len(&text));
+            Cow::Owned(text)
+        } else {
+            Cow::Borrowed(&raw[..trimmed_len(raw)])
+        };
+
+        Self { text }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// The lines of the input, skipping any that are empty.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        lines(&self.text)
+    }
+
+    /// The blocks of the input separated by blank lines.
+    pub fn paragraphs(&self) -> impl Iterator<Item = Input<'_>> {
+        self.text
+            .split("\n\n")
+            .map(|paragraph| paragraph.trim_matches('\n'))
+            .filter(|paragraph| !paragraph.is_empty())
+            .map(Input::new)
+    }
+
+    /// Parses the input as a grid of characters.
+    pub fn grid<T: TryFrom<char, Error = AocError>>(&self) -> Result<Grid<T>, AocError> {
+        Grid::try_parse(&self.text, T::try_from)
+    }
+
+    /// Every integer in the input, in order.
+    ///
+    /// A `-` only counts as a sign when it doesn't follow a digit, so ranges like
+    /// `11-22` give `11` and `22`.
+    pub fn ints<T: FromStr>(&self) -> Result<Vec<T>, AocError> {
+        let bytes = self.text.as_bytes();
+        let mut ints = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let negative = bytes[i] == b'-'
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+                && (i == 0 || !bytes[i - 1].is_ascii_digit());
+            if negative || bytes[i].is_ascii_digit() {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                ints.push(number(&self.text[start..i])?);
+            } else {
+                i += 1;
+            }
+        }
+        Ok(ints)
+    }
+
+    /// Splits each line in two around the first `sep`, failing on the first
+    /// line without one.
+    pub fn split_pairs<'s>(
+        &'s self,
+        sep: &'s str,
+    ) -> impl Iterator<Item = Result<(&'s str, &'s str), AocError>> {
+        self.lines().map(move |line| split_once(line, sep))
+    }
+}
+
+impl<'a> From<&'a str> for Input<'a> {
+    fn from(raw: &'a str) -> Self {
+        Self::new(raw)
+    }
+}
+
+impl Deref for Input<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(lines("a\nb\n\nc\n").collect::<Vec<&str>>(), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_normalise() {
+        assert_eq!(Input::new("a\r\nb \r\n\r\n").as_str(), "a\nb ");
+        assert_eq!(Input::new("a\n  \n\n\n").as_str(), "a");
+        assert_eq!(Input::new(" 1 \n 2 \n").as_str(), " 1 \n 2 ");
+        assert_eq!(Input::new("\n\n").as_str(), "");
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let input = Input::new("a\nb\n\nc\n\n\nd\n");
+        let paragraphs: Vec<String> = input.paragraphs().map(|p| p.to_string()).collect();
+
+        assert_eq!(paragraphs, ["a\nb", "c", "d"]);
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(
+            Input::new("11-22,95-115").ints::<u32>(),
+            Ok(vec![11, 22, 95, 115])
+        );
+        assert_eq!(Input::new("x=-3, y=4-5").ints::<i32>(), Ok(vec![-3, 4, 5]));
+
+        let input = Input::new("1 2\n3 300\n");
+        let err = input.ints::<u8>().unwrap_err().locate(&input);
+        assert_eq!(err.text, "300");
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 3)));
+    }
+
+    #[test]
+    fn test_split_pairs() {
+        let input = Input::new("3-5\n10-14\n");
+        let pairs: Result<Vec<(&str, &str)>, _> = input.split_pairs("-").collect();
+        assert_eq!(pairs, Ok(vec![("3", "5"), ("10", "14")]));
+
+        let input = Input::new("3-5\n10\n");
+        let pairs: Result<Vec<(&str, &str)>, _> = input.split_pairs("-").collect();
+        let err = pairs.unwrap_err().locate(&input);
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 3)));
+    }
+
+    #[test]
+    fn test_search_order() {
+        let root = scratch_dir("order");
+        fs::write(root.join("input.txt"), "legacy").unwrap();
+        assert_eq!(load_input_from(&root, 2015, 7, None).unwrap(), "legacy");
+
+        fs::create_dir_all(root.join("inputs/2015")).unwrap();
+        fs::write(root.join("inputs/2015/7.txt"), "inputs").unwrap();
+        assert_eq!(load_input_from(&root, 2015, 7, None).unwrap(), "inputs");
+
+        let explicit = root.join("other.txt");
+        fs::write(&explicit, "explicit").unwrap();
+        assert_eq!(
+            load_input_from(&root, 2015, 7, explicit.to_str()).unwrap(),
+            "explicit"
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_not_found_names_paths() {
+        let root = scratch_dir("missing");
+        let err = load_input_from(&root, 2025, 3, None)
+            .unwrap_err()
+            .to_string();
+
+        for path in candidates(&root, 2025, 3) {
+            assert!(err.contains(&path.display().to_string()), "{}", err);
+        }
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
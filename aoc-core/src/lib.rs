@@ -0,0 +1,20 @@
+pub mod alloc;
+pub mod answer;
+pub mod bench;
+pub mod diff;
+pub mod error;
+pub mod fuzz;
+pub mod grid;
+pub mod input;
+pub mod logging;
+pub mod point;
+pub mod random;
+pub mod solution;
+
+pub use answer::Answer;
+pub use error::AocError;
+pub use grid::Grid;
+pub use input::{Input, InputError, lines, load_input};
+pub use point::Point;
+pub use random::Rng;
+pub use solution::{Assumption, DayResult, DynSolution, Erased, Part, Solution, Timed, run};
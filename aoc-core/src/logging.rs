@@ -0,0 +1,232 @@
+//! Trace output from the days, silent unless asked for.
+//!
+//! Days log with the `tracing` macros as usual. Which events are shown is set
+//! by the verbosity, `-v` for debug and `-vv` for trace, and refined by
+//! `AOC_LOG`, a comma separated list of directives such as
+//! `2015::d13=trace,2025=debug`.
+
+use std::{
+    env,
+    fmt::Display,
+    io::{self, IsTerminal},
+    str::FromStr,
+};
+
+use tracing::{Level, level_filters::LevelFilter};
+use tracing_subscriber::{Layer, filter::FilterFn, layer::SubscriberExt, util::SubscriberInitExt};
+
+pub const LOG_VAR: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogError {
+    BadLevel(String),
+    BadDirective(String),
+}
+
+impl Display for LogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BadLevel(level) => write!(
+                f,
+                "{}: unknown level {:?}, expected one of off, error, warn, info, debug or trace",
+                LOG_VAR, level
+            ),
+            Self::BadDirective(directive) => write!(
+                f,
+                "{}: unable to read {:?}, expected something like `2015::d13=trace`",
+                LOG_VAR, directive
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LogError {}
+
+/// What a directive applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Scope {
+    /// Every day of a year, written `2015`.
+    Year(u16),
+    /// A single day, written `2015::d13`, or any other crate or module.
+    Target(String),
+}
+
+impl Scope {
+    fn matches(&self, target: &str) -> bool {
+        match self {
+            Self::Year(year) => target.starts_with(&format!("y{}_d", year)),
+            Self::Target(prefix) => target
+                .strip_prefix(prefix.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::")),
+        }
+    }
+}
+
+impl FromStr for Scope {
+    type Err = LogError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad = || LogError::BadDirective(s.to_owned());
+        if s.is_empty() {
+            return Err(bad());
+        }
+
+        let (year, day) = s.split_once("::").unwrap_or((s, ""));
+        let Ok(year) = u16::from_str_radix(year, 10) else {
+            return Ok(Self::Target(s.to_owned()));
+        };
+        if day.is_empty() {
+            return Ok(Self::Year(year));
+        }
+        // NOTE: day crates are named after their package, `y2015-d13` logs as `y2015_d13`
+        let day = day
+            .strip_prefix('d')
+            .and_then(|day| u8::from_str_radix(day, 10).ok())
+            .ok_or_else(bad)?;
+        Ok(Self::Target(format!("y{}_d{}", year, day)))
+    }
+}
+
+/// Which events to show, worked out from the verbosity and `AOC_LOG`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    default: LevelFilter,
+    directives: Vec<(Scope, LevelFilter)>,
+}
+
+fn level(text: &str) -> Result<LevelFilter, LogError> {
+    LevelFilter::from_str(text).map_err(|_| LogError::BadLevel(text.to_owned()))
+}
+
+impl LogFilter {
+    /// Shows warnings by default, debug for `-v` and trace for `-vv`.
+    pub fn new(verbose: u8) -> Self {
+        let default = match verbose {
+            0 => LevelFilter::WARN,
+            1 => LevelFilter::DEBUG,
+            _ => LevelFilter::TRACE,
+        };
+        Self {
+            default,
+            directives: Vec::new(),
+        }
+    }
+
+    /// Adds the directives in `spec`, which take precedence over the
+    /// verbosity. A directive without a level means trace, and a level on its
+    /// own replaces the default.
+    pub fn parse(mut self, spec: &str) -> Result<Self, LogError> {
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((scope, level_text)) => {
+                    let scope = scope.trim().parse()?;
+                    self.directives.push((scope, level(level_text.trim())?));
+                }
+                None => match level(directive) {
+                    Ok(level) => self.default = level,
+                    Err(_) => self
+                        .directives
+                        .push((directive.parse()?, LevelFilter::TRACE)),
+                },
+            }
+        }
+        Ok(self)
+    }
+
+    /// The level shown for events from `target`.
+    ///
+    /// A day or module directive wins over a year one, and a later directive
+    /// over an earlier one of the same kind.
+    pub fn level(&self, target: &str) -> LevelFilter {
+        let mut found: Option<(bool, LevelFilter)> = None;
+        for (scope, level) in &self.directives {
+            if !scope.matches(target) {
+                continue;
+            }
+            let specific = matches!(scope, Scope::Target(_));
+            if found.is_none_or(|(was_specific, _)| specific || !was_specific) {
+                found = Some((specific, *level));
+            }
+        }
+        found.map_or(self.default, |(_, level)| level)
+    }
+
+    pub fn enabled(&self, target: &str, level: &Level) -> bool {
+        self.level(target) >= *level
+    }
+
+    /// The most verbose level anything could be shown at.
+    fn max_level(&self) -> LevelFilter {
+        self.directives
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, LevelFilter::max)
+    }
+}
+
+/// Sends events to stderr, keeping stdout for answers.
+///
+/// Only the first call does anything, later ones are ignored.
+pub fn init(verbose: u8) -> Result<(), LogError> {
+    let mut filter = LogFilter::new(verbose);
+    if let Ok(spec) = env::var(LOG_VAR) {
+        filter = filter.parse(&spec)?;
+    }
+
+    let max_level = filter.max_level();
+    let layer = tracing_subscriber::fmt::layer()
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .with_filter(
+            FilterFn::new(move |meta| filter.enabled(meta.target(), meta.level()))
+                .with_max_level_hint(max_level),
+        );
+    let _ = tracing_subscriber::registry().with(layer).try_init();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity() {
+        assert!(!LogFilter::new(0).enabled("y2015_d13", &Level::DEBUG));
+        assert!(LogFilter::new(0).enabled("y2015_d13", &Level::WARN));
+        assert!(LogFilter::new(1).enabled("y2015_d13", &Level::DEBUG));
+        assert!(!LogFilter::new(1).enabled("y2015_d13", &Level::TRACE));
+        assert!(LogFilter::new(2).enabled("y2015_d13", &Level::TRACE));
+    }
+
+    #[test]
+    fn test_directives() {
+        let filter = LogFilter::new(0)
+            .parse("2015=debug, 2015::d13=trace,2025::d6=off,aoc_core")
+            .unwrap();
+
+        assert_eq!(filter.level("y2015_d13"), LevelFilter::TRACE);
+        assert_eq!(filter.level("y2015_d13::parser"), LevelFilter::TRACE);
+        // a day's directive doesn't reach days that share its prefix
+        assert_eq!(filter.level("y2015_d1"), LevelFilter::DEBUG);
+        assert_eq!(filter.level("y2025_d6"), LevelFilter::OFF);
+        assert_eq!(filter.level("y2025_d7"), LevelFilter::WARN);
+        assert_eq!(filter.level("aoc_core::solution"), LevelFilter::TRACE);
+
+        let filter = LogFilter::new(2).parse("info,2015::d1=debug").unwrap();
+        assert_eq!(filter.level("y2025_d1"), LevelFilter::INFO);
+        assert_eq!(filter.level("y2015_d1"), LevelFilter::DEBUG);
+    }
+
+    #[test]
+    fn test_bad_directives() {
+        assert_eq!(
+            LogFilter::new(0).parse("2015::d13=loud"),
+            Err(LogError::BadLevel(String::from("loud")))
+        );
+        assert_eq!(
+            LogFilter::new(0).parse("2015::13=trace"),
+            Err(LogError::BadDirective(String::from("2015::13")))
+        );
+    }
+}
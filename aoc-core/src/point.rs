@@ -0,0 +1,49 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    AocError,
+    error::{number, split_once},
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Parses a point written as `x,y`.
+impl<T: FromStr> FromStr for Point<T> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = split_once(s.trim(), ",")?;
+        Ok(Self {
+            x: number(x.trim())?,
+            y: number(y.trim())?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3,4".parse::<Point<u32>>().ok(), Some(Point::new(3, 4)));
+        assert!("3;4".parse::<Point<u32>>().is_err());
+        assert!("3,x".parse::<Point<u32>>().is_err());
+    }
+}
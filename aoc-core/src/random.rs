@@ -0,0 +1,136 @@
+//! Random puzzle inputs, for stress testing and benchmarking beyond a single
+//! real input.
+//!
+//! Generators only need to be reproducible from their seed, so this is a small
+//! SplitMix64 rather than a dependency.
+
+use std::ops::RangeInclusive;
+
+/// A seeded source of random numbers.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, which must not be empty.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "nothing below 0 to pick");
+        // NOTE: the bias from the modulo is too small to matter for inputs
+        self.next_u64() % n
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {}..={}", start, end);
+        let width = end.abs_diff(start).wrapping_add(1);
+        match width {
+            // NOTE: the whole of i64
+            0 => self.next_u64() as i64,
+            _ => start.wrapping_add(self.below(width) as i64),
+        }
+    }
+
+    /// An index into something of length `len`.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / ((1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    /// A lowercase word of `len` letters.
+    pub fn word(&mut self, len: usize) -> String {
+        (0..len)
+            .map(|_| (b'a' + self.below(26) as u8) as char)
+            .collect()
+    }
+
+    /// The fewest letters that leave plenty of room for `count` different
+    /// words, so [`Rng::words`] doesn't struggle to find them.
+    pub fn letters_for(count: usize) -> usize {
+        let mut len = 1;
+        while 26usize.pow(len as u32) < 2 * count {
+            len += 1;
+        }
+        len
+    }
+
+    /// `count` different lowercase words of `len` letters, leaving out any in
+    /// `taken`.
+    pub fn words(&mut self, count: usize, len: usize, taken: &[&str]) -> Vec<String> {
+        let mut words: Vec<String> = Vec::with_capacity(count);
+        while words.len() < count {
+            let word = self.word(len);
+            if !taken.contains(&word.as_str()) && !words.contains(&word) {
+                words.push(word);
+            }
+        }
+        words
+    }
+
+    /// `count` different capitalised names, like `Alice`.
+    pub fn names(&mut self, count: usize) -> Vec<String> {
+        let mut names = self.words(count, 6, &[]);
+        for name in &mut names {
+            name[..1].make_ascii_uppercase();
+        }
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        let draws: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        assert_eq!(draws, (0..5).map(|_| b.next_u64()).collect::<Vec<u64>>());
+        assert_ne!(Rng::new(8).next_u64(), draws[0]);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3..=3)));
+            assert!(rng.below(10) < 10);
+        }
+        assert_eq!(rng.range(5..=5), 5);
+        rng.range(i64::MIN..=i64::MAX);
+
+        let mut items = [1, 2, 3, 4, 5];
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+        assert_eq!(rng.words(20, 1, &["a"]).len(), 20);
+    }
+}
@@ -0,0 +1,395 @@
+use std::{
+    fmt::Display,
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Answer, AocError, Input,
+    alloc::{AllocStats, track},
+    bench::{Budget, Stage, Stats, measure},
+    load_input,
+    random::Rng,
+};
+
+/// A single day's puzzle, split into parsing and the two parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Parsed;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError>;
+    fn part1(input: &Self::Parsed) -> Self::Part1;
+    fn part2(input: &Self::Parsed) -> Self::Part2;
+
+    /// What the parts rely on about the input beyond what parsing checks.
+    fn assumptions() -> Vec<Assumption<Self::Parsed>> {
+        Vec::new()
+    }
+
+    /// A random input that `parse` accepts and the parts can solve, or `None`
+    /// if the day has no generator.
+    ///
+    /// A `scale` of 1 gives something about the size of a real input, and
+    /// larger values something bigger, by however much suits the day.
+    fn generate(_rng: &mut Rng, _scale: usize) -> Option<String> {
+        None
+    }
+}
+
+/// Something a solution takes for granted about its input, like a count
+/// that only the real input happens to have.
+pub struct Assumption<T> {
+    /// Read as "assumes that ...", such as "there are six shapes".
+    pub name: &'static str,
+    pub holds: fn(&T) -> bool,
+}
+
+impl<T> Assumption<T> {
+    pub const fn new(name: &'static str, holds: fn(&T) -> bool) -> Self {
+        Self { name, holds }
+    }
+}
+
+/// The names of the assumptions of `S` that `parsed` breaks.
+fn broken<S: Solution>(parsed: &S::Parsed) -> Vec<&'static str> {
+    S::assumptions()
+        .into_iter()
+        .filter(|assumption| !(assumption.holds)(parsed))
+        .map(|assumption| assumption.name)
+        .collect()
+}
+
+/// Fails if `parsed` breaks any assumption of `S`, as the parts could then
+/// panic or quietly give a wrong answer.
+fn assume<S: Solution>(parsed: &S::Parsed) -> Result<(), AocError> {
+    match broken::<S>(parsed).as_slice() {
+        [] => Ok(()),
+        names => Err(AocError::expected(format!(
+            "an input where {}",
+            names.join(" and ")
+        ))),
+    }
+}
+
+/// Solves both parts of a day, taking an optional input path as the first argument.
+pub fn run<S: Solution>() {
+    if let Err(err) = crate::logging::init(0) {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+    let explicit = std::env::args().nth(1);
+    let input = match load_input(S::YEAR, S::DAY, explicit.as_deref()) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    };
+    let input = Input::new(&input);
+    let parsed = match S::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{}", err.locate(&input));
+            std::process::exit(1);
+        }
+    };
+    if let Err(err) = assume::<S>(&parsed) {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+
+    let part1: Answer = S::part1(&parsed).into();
+    println!("{} day {} part 1: {}", S::YEAR, S::DAY, part1);
+    let part2: Answer = S::part2(&parsed).into();
+    println!("{} day {} part 2: {}", S::YEAR, S::DAY, part2);
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// An answer along with how long it took to compute.
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub answer: Answer,
+    pub elapsed: Duration,
+    /// Only recorded with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+}
+
+/// The outcome of running a single day.
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Duration,
+    pub parse_alloc: Option<AllocStats>,
+    pub parts: Vec<(Part, Timed)>,
+}
+
+/// An object safe view of a [`Solution`], so days can be stored side by side.
+pub trait DynSolution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+
+    /// Parses `input` and solves the requested part, or both if `part` is `None`.
+    ///
+    /// Parse errors come back already located in `input`. An input that
+    /// breaks one of the day's assumptions is an error too, and isn't solved.
+    fn solve(&self, input: &str, part: Option<Part>) -> Result<DayResult, AocError>;
+
+    /// Times parsing and each part separately.
+    fn bench(&self, input: &str, budget: &Budget) -> Result<Vec<(Stage, Stats)>, AocError>;
+
+    /// Parses `input` and nothing more, for fuzzing the parser.
+    fn parse(&self, input: &str) -> Result<(), AocError>;
+
+    /// How many assumptions the day makes, and the names of those `input` breaks.
+    fn check(&self, input: &str) -> Result<(usize, Vec<&'static str>), AocError>;
+
+    /// A random input from `seed`, checked to parse, or `None` if the day has
+    /// no generator.
+    fn generate(&self, seed: u64, scale: usize) -> Option<Result<String, AocError>>;
+}
+
+/// Wraps a [`Solution`] type so it can be used as a [`DynSolution`].
+pub struct Erased<S>(PhantomData<fn() -> S>);
+
+impl<S> Erased<S> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for Erased<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn timed<T: Into<Answer>>(f: impl FnOnce() -> T) -> Timed {
+    let start = Instant::now();
+    let (answer, alloc) = track(f);
+    let elapsed = start.elapsed();
+
+    Timed {
+        answer: answer.into(),
+        elapsed,
+        alloc,
+    }
+}
+
+impl<S: Solution> DynSolution for Erased<S> {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve(&self, input: &str, part: Option<Part>) -> Result<DayResult, AocError> {
+        let input = Input::new(input);
+        let start = Instant::now();
+        let (parsed, parse_alloc) = track(|| S::parse(&input));
+        let parse = start.elapsed();
+        let parsed = parsed.map_err(|err| err.locate(&input))?;
+        assume::<S>(&parsed)?;
+
+        let mut parts = Vec::with_capacity(2);
+        if part != Some(Part::Two) {
+            parts.push((Part::One, timed(|| S::part1(&parsed))));
+        }
+        if part != Some(Part::One) {
+            parts.push((Part::Two, timed(|| S::part2(&parsed))));
+        }
+
+        Ok(DayResult {
+            year: S::YEAR,
+            day: S::DAY,
+            parse,
+            parse_alloc,
+            parts,
+        })
+    }
+
+    fn bench(&self, input: &str, budget: &Budget) -> Result<Vec<(Stage, Stats)>, AocError> {
+        let input = Input::new(input);
+        let parsed = S::parse(&input).map_err(|err| err.locate(&input))?;
+        assume::<S>(&parsed)?;
+
+        Ok(vec![
+            (Stage::Parse, measure(budget, || S::parse(&input))),
+            (
+                Stage::Part(Part::One),
+                measure(budget, || S::part1(&parsed)),
+            ),
+            (
+                Stage::Part(Part::Two),
+                measure(budget, || S::part2(&parsed)),
+            ),
+        ])
+    }
+
+    fn parse(&self, input: &str) -> Result<(), AocError> {
+        let input = Input::new(input);
+        S::parse(&input).map_err(|err| err.locate(&input))?;
+        Ok(())
+    }
+
+    fn check(&self, input: &str) -> Result<(usize, Vec<&'static str>), AocError> {
+        let input = Input::new(input);
+        let parsed = S::parse(&input).map_err(|err| err.locate(&input))?;
+        Ok((S::assumptions().len(), broken::<S>(&parsed)))
+    }
+
+    fn generate(&self, seed: u64, scale: usize) -> Option<Result<String, AocError>> {
+        let text = S::generate(&mut Rng::new(seed), scale)?;
+        let input = Input::new(&text);
+        if let Err(err) = S::parse(&input) {
+            return Some(Err(err.locate(&input)));
+        }
+        Some(Ok(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Parsed = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = Answer;
+
+        fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+            input.ints()
+        }
+
+        fn part1(input: &Self::Parsed) -> Self::Part1 {
+            input.iter().sum()
+        }
+
+        fn part2(_: &Self::Parsed) -> Self::Part2 {
+            Answer::Unsolved
+        }
+
+        fn assumptions() -> Vec<Assumption<Self::Parsed>> {
+            vec![
+                Assumption::new("there are three numbers", |nums| nums.len() == 3),
+                Assumption::new("every number is a digit", |nums| {
+                    nums.iter().all(|n| *n < 10)
+                }),
+            ]
+        }
+
+        fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+            let limit = 10u64.pow(scale as u32);
+            Some((0..3).map(|_| format!("{}\n", rng.below(limit))).collect())
+        }
+    }
+
+    #[test]
+    fn test_erased_check() {
+        let solution: &dyn DynSolution = &Erased::<Sum>::new();
+
+        assert_eq!(solution.check("1\n2\n3\n"), Ok((2, vec![])));
+        assert_eq!(
+            solution.check("1\n20\n"),
+            Ok((
+                2,
+                vec!["there are three numbers", "every number is a digit"]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_erased_generate() {
+        let solution: &dyn DynSolution = &Erased::<Sum>::new();
+        let text = solution.generate(4, 1).unwrap().unwrap();
+
+        assert_eq!(solution.check(&text), Ok((2, vec![])));
+        assert_eq!(solution.generate(4, 1), Some(Ok(text)));
+        // numbers of up to twelve digits don't fit in a u32
+        assert!(solution.generate(4, 12).unwrap().is_err());
+    }
+
+    #[test]
+    fn test_erased_both_parts() {
+        let solution: &dyn DynSolution = &Erased::<Sum>::new();
+        let result = solution.solve("1\n2\n3\n", None).unwrap();
+
+        assert_eq!((result.year, result.day), (2000, 1));
+        let answers: Vec<(Part, &Answer)> = result
+            .parts
+            .iter()
+            .map(|(part, timed)| (*part, &timed.answer))
+            .collect();
+        assert_eq!(
+            answers,
+            [
+                (Part::One, &Answer::Unsigned(6)),
+                (Part::Two, &Answer::Unsolved)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_erased_single_part() {
+        let result = Erased::<Sum>::new()
+            .solve("1\n2\n3\n", Some(Part::Two))
+            .unwrap();
+
+        assert_eq!(result.parts.len(), 1);
+        assert_eq!(result.parts[0].0, Part::Two);
+    }
+
+    #[test]
+    fn test_erased_parse_error() {
+        let err = Erased::<Sum>::new()
+            .solve("1\n99999999999\n", None)
+            .unwrap_err();
+
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_erased_broken_assumption() {
+        let err = Erased::<Sum>::new().solve("1\n2\n", None).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "expected an input where there are three numbers"
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::Type;
+
+fn error(message: String) -> syn::Error {
+    syn::Error::new(Span::call_site(), message)
+}
+
+/// Finds `examples/<year>/<day>` from a day crate living at `y<year>/d<day>`.
+fn examples_dir(manifest_dir: &Path) -> syn::Result<PathBuf> {
+    let name = |path: Option<&Path>, prefix: char| {
+        path.and_then(Path::file_name)
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix(prefix))
+            .filter(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+            .map(str::to_owned)
+    };
+
+    let day_dir = Some(manifest_dir);
+    let year_dir = manifest_dir.parent();
+    match (name(year_dir, 'y'), name(day_dir, 'd')) {
+        (Some(year), Some(day)) => Ok(year_dir
+            .and_then(Path::parent)
+            .unwrap()
+            .join("examples")
+            .join(year)
+            .join(day)),
+        _ => Err(error(format!(
+            "{} isn't laid out as y<year>/d<day>",
+            manifest_dir.display()
+        ))),
+    }
+}
+
+pub fn expand(day: &Type) -> syn::Result<TokenStream> {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| error(String::from("CARGO_MANIFEST_DIR isn't set")))?;
+    let dir = examples_dir(Path::new(&manifest_dir))?;
+
+    let answers_path = dir.join("answers.toml");
+    let text = fs::read_to_string(&answers_path).map_err(|err| {
+        error(format!(
+            "unable to read {}: {}",
+            answers_path.display(),
+            err
+        ))
+    })?;
+    let table: toml::Table = text.parse().map_err(|err| {
+        error(format!(
+            "unable to parse {}: {}",
+            answers_path.display(),
+            err
+        ))
+    })?;
+
+    let mut tests = Vec::new();
+    for (key, parts) in table {
+        let n = u32::from_str_radix(&key, 10)
+            .map_err(|_| error(format!("{:?} is not an example number", key)))?;
+        let input_path = dir.join(format!("{}.txt", n));
+        if !input_path.exists() {
+            return Err(error(format!("{} doesn't exist", input_path.display())));
+        }
+        let input_path = input_path.display().to_string();
+
+        let parts = parts
+            .as_table()
+            .ok_or_else(|| error(format!("example {} should be a table", n)))?;
+        for (part, expected) in parts {
+            let method = match part.as_str() {
+                "part1" => format_ident!("part1"),
+                "part2" => format_ident!("part2"),
+                other => return Err(error(format!("example {} has unknown key {:?}", n, other))),
+            };
+            let expected = match expected {
+                toml::Value::Integer(n) => n.to_string(),
+                toml::Value::String(s) => s.clone(),
+                other => {
+                    return Err(error(format!(
+                        "example {} {} should be an integer or string, not {}",
+                        n,
+                        part,
+                        other.type_str()
+                    )));
+                }
+            };
+            let name = format_ident!("example_{}_{}", n, part);
+
+            tests.push(quote! {
+                #[test]
+                fn #name() {
+                    let input = ::aoc_core::Input::new(include_str!(#input_path));
+                    let parsed = <#day as ::aoc_core::Solution>::parse(&input)
+                        .unwrap_or_else(|err| panic!("{}", err.locate(&input)));
+                    let answer: ::aoc_core::Answer =
+                        <#day as ::aoc_core::Solution>::#method(&parsed).into();
+                    let expected: ::aoc_core::Answer = #expected.parse().unwrap();
+
+                    assert_eq!(answer, expected);
+                }
+            });
+        }
+    }
+
+    let answers_path = answers_path.display().to_string();
+    let examples = (!tests.is_empty()).then(|| {
+        quote! {
+            mod examples {
+                use super::*;
+
+                #(#tests)*
+            }
+        }
+    });
+
+    Ok(quote! {
+        // NOTE: rebuilds the tests whenever an answer is added
+        const _: &str = include_str!(#answers_path);
+
+        #examples
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples_dir() {
+        assert_eq!(
+            examples_dir(Path::new("/repo/y2015/d7")).unwrap(),
+            Path::new("/repo/examples/2015/7")
+        );
+        assert!(examples_dir(Path::new("/repo/aoc-core")).is_err());
+    }
+}
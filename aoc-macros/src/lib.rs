@@ -0,0 +1,52 @@
+use proc_macro::TokenStream;
+
+mod examples;
+mod parse;
+
+/// Generates a test for every answer recorded in the day's example directory.
+///
+/// The day is worked out from the crate's location, so for `y2015/d7` the
+/// examples live in `examples/2015/7/` at the workspace root. Each `<n>.txt` is
+/// an example input and `answers.toml` holds the expected answers, in the same
+/// format as the accepted answers:
+///
+/// ```toml
+/// [1]
+/// part1 = 4
+/// part2 = 3
+/// ```
+///
+/// Parts without an answer are skipped, as not every example covers both parts.
+#[proc_macro]
+pub fn example_tests(input: TokenStream) -> TokenStream {
+    let day = syn::parse_macro_input!(input as syn::Type);
+
+    examples::expand(&day)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `FromStr` for a struct by matching each line against a pattern,
+/// with every `{field}` read by its type's `aoc_core::error::Field` impl:
+///
+/// ```ignore
+/// #[derive(AocParse)]
+/// #[aoc(pattern = "{name} can fly {speed} km/s")]
+/// struct Reindeer {
+///     name: String,
+///     speed: u64,
+/// }
+/// ```
+///
+/// A field runs up to the first match of the text after it in the pattern.
+/// Enums of unit variants give the text for each variant instead, such as
+/// `#[aoc(pattern = "gain")]`, and can then be used as fields. Errors point at
+/// the part of the line that didn't match.
+#[proc_macro_derive(AocParse, attributes(aoc))]
+pub fn aoc_parse(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    parse::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
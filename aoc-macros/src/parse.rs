@@ -0,0 +1,240 @@
+use std::collections::HashSet;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Fields, Ident, LitStr};
+
+/// A pattern broken into the text that must match exactly and the fields
+/// read from between it.
+#[derive(Debug, PartialEq)]
+enum Piece {
+    Literal(String),
+    Field(String),
+}
+
+fn pieces(pattern: &str) -> Result<Vec<Piece>, String> {
+    let mut pieces = Vec::new();
+    let mut rest = pattern;
+    while !rest.is_empty() {
+        let Some(open) = rest.find('{') else {
+            if rest.contains('}') {
+                return Err(String::from("`}` without a `{` before it"));
+            }
+            pieces.push(Piece::Literal(rest.to_owned()));
+            break;
+        };
+        if open > 0 {
+            if rest[..open].contains('}') {
+                return Err(String::from("`}` without a `{` before it"));
+            }
+            pieces.push(Piece::Literal(rest[..open].to_owned()));
+        }
+        let close = rest[open..]
+            .find('}')
+            .ok_or_else(|| String::from("`{` without a `}` after it"))?;
+        let name = &rest[open + 1..open + close];
+        if syn::parse_str::<syn::Ident>(name).is_err() {
+            return Err(format!("{:?} isn't a field name", name));
+        }
+        if let Some(Piece::Field(before)) = pieces.last() {
+            return Err(format!(
+                "nothing separates {{{}}} from {{{}}}",
+                before, name
+            ));
+        }
+        pieces.push(Piece::Field(name.to_owned()));
+        rest = &rest[open + close + 1..];
+    }
+    Ok(pieces)
+}
+
+/// The `pattern` in an `#[aoc(...)]` attribute, if there is one.
+fn pattern(attrs: &[Attribute]) -> syn::Result<Option<LitStr>> {
+    let mut pattern = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("aoc")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("pattern") {
+                pattern = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `pattern`"))
+            }
+        })?;
+    }
+    Ok(pattern)
+}
+
+fn expand_struct(input: &DeriveInput, fields: &Fields) -> syn::Result<TokenStream> {
+    let pattern = pattern(&input.attrs)?.ok_or_else(|| {
+        syn::Error::new_spanned(
+            &input.ident,
+            "expected a pattern such as #[aoc(pattern = \"{name}: {size}\")]",
+        )
+    })?;
+    let Fields::Named(fields) = fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "only structs with named fields can be parsed from a pattern",
+        ));
+    };
+    let pieces = pieces(&pattern.value()).map_err(|err| syn::Error::new(pattern.span(), err))?;
+
+    // NOTE: hygienic, so a field can be called `rest` too
+    let rest = Ident::new("rest", Span::mixed_site());
+    let mut seen = HashSet::new();
+    let mut steps = Vec::new();
+    let mut pieces = pieces.iter().peekable();
+    while let Some(piece) = pieces.next() {
+        let name = match piece {
+            Piece::Literal(literal) => {
+                steps.push(quote! {
+                    let #rest = ::aoc_core::error::prefix(#rest, #literal)?;
+                });
+                continue;
+            }
+            Piece::Field(name) => name,
+        };
+        let field = fields
+            .named
+            .iter()
+            .find(|field| field.ident.as_ref().is_some_and(|ident| ident == name))
+            .ok_or_else(|| syn::Error::new(pattern.span(), format!("there's no field {}", name)))?;
+        if !seen.insert(name.clone()) {
+            return Err(syn::Error::new(
+                pattern.span(),
+                format!("{} is in the pattern twice", name),
+            ));
+        }
+
+        let (ident, ty) = (format_ident!("{}", name), &field.ty);
+        // NOTE: a field runs up to the first match of the text after it, or the end
+        match pieces.peek() {
+            Some(Piece::Literal(next)) => steps.push(quote! {
+                let (#ident, #rest) = ::aoc_core::error::until(#rest, #next);
+            }),
+            _ => steps.push(quote! {
+                let (#ident, #rest) = (#rest, &#rest[#rest.len()..]);
+            }),
+        }
+        steps.push(quote! {
+            let #ident = <#ty as ::aoc_core::error::Field>::field(#ident)?;
+        });
+    }
+    if let Some(missing) = fields
+        .named
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .find(|ident| !seen.contains(&ident.to_string()))
+    {
+        return Err(syn::Error::new(
+            pattern.span(),
+            format!("the pattern has no {{{}}}", missing),
+        ));
+    }
+
+    let name = &input.ident;
+    let idents = fields.named.iter().map(|field| &field.ident);
+    Ok(quote! {
+        impl ::std::str::FromStr for #name {
+            type Err = ::aoc_core::AocError;
+
+            fn from_str(text: &str) -> ::std::result::Result<Self, Self::Err> {
+                let #rest = text;
+                #(#steps)*
+                ::aoc_core::error::end(#rest)?;
+                Ok(Self { #(#idents),* })
+            }
+        }
+
+        impl ::aoc_core::error::Field for #name {
+            fn field(text: &str) -> ::std::result::Result<Self, ::aoc_core::AocError> {
+                text.parse()
+            }
+        }
+    })
+}
+
+fn expand_enum(input: &DeriveInput, data: &syn::DataEnum) -> syn::Result<TokenStream> {
+    let mut arms = Vec::new();
+    let mut words = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "only variants without fields can be parsed from a pattern",
+            ));
+        }
+        let word = pattern(&variant.attrs)?.ok_or_else(|| {
+            syn::Error::new_spanned(
+                variant,
+                "expected the text for it, like #[aoc(pattern = \"on\")]",
+            )
+        })?;
+        let ident = &variant.ident;
+        arms.push(quote! { #word => Ok(Self::#ident), });
+        words.push(format!("`{}`", word.value()));
+    }
+    let expected = match words.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "an enum without variants can't be parsed",
+            ));
+        }
+    };
+
+    let name = &input.ident;
+    Ok(quote! {
+        impl ::std::str::FromStr for #name {
+            type Err = ::aoc_core::AocError;
+
+            fn from_str(text: &str) -> ::std::result::Result<Self, Self::Err> {
+                match text {
+                    #(#arms)*
+                    _ => Err(::aoc_core::AocError::new(text, #expected)),
+                }
+            }
+        }
+
+        impl ::aoc_core::error::Field for #name {
+            fn field(text: &str) -> ::std::result::Result<Self, ::aoc_core::AocError> {
+                text.parse()
+            }
+        }
+    })
+}
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    match &input.data {
+        Data::Struct(data) => expand_struct(input, &data.fields),
+        Data::Enum(data) => expand_enum(input, data),
+        Data::Union(_) => Err(syn::Error::new_spanned(
+            &input.ident,
+            "unions can't be parsed from a pattern",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pieces() {
+        assert_eq!(
+            pieces("{name}: capacity {capacity}.").unwrap(),
+            [
+                Piece::Field(String::from("name")),
+                Piece::Literal(String::from(": capacity ")),
+                Piece::Field(String::from("capacity")),
+                Piece::Literal(String::from(".")),
+            ]
+        );
+        assert!(pieces("{a}{b}").is_err());
+        assert!(pieces("{a").is_err());
+        assert!(pieces("a} b").is_err());
+        assert!(pieces("{1a} b").is_err());
+    }
+}
@@ -0,0 +1,173 @@
+use std::{
+    collections::{BTreeMap, btree_map::Entry},
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::{Answer, Part};
+
+/// Directory holding the accepted answers, one `<year>.toml` per year.
+pub const ANSWERS_DIR: &str = "answers";
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Read { path: PathBuf, err: io::Error },
+    Parse { path: PathBuf, err: toml::de::Error },
+    Invalid { path: PathBuf, message: String },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read { path, err } => write!(f, "unable to read {}: {}", path.display(), err),
+            Self::Parse { path, err } => write!(f, "unable to parse {}: {}", path.display(), err),
+            Self::Invalid { path, message } => write!(f, "{}: {}", path.display(), message),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// The accepted answers for a single year.
+///
+/// Each day is a table keyed by its number, with `part1` and `part2` holding
+/// either an integer or a string:
+///
+/// ```toml
+/// [7]
+/// part1 = 956
+/// part2 = "abcdffaa"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Answers {
+    days: BTreeMap<u8, [Option<Answer>; 2]>,
+}
+
+fn part_index(part: Part) -> usize {
+    match part {
+        Part::One => 0,
+        Part::Two => 1,
+    }
+}
+
+impl Answers {
+    pub fn path(year: u16) -> PathBuf {
+        Path::new(ANSWERS_DIR).join(format!("{}.toml", year))
+    }
+
+    /// Loads the answers for `year`, treating a missing file as having none recorded.
+    pub fn load(year: u16) -> Result<Self, AnswersError> {
+        let path = Self::path(year);
+        match fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text, &path),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(AnswersError::Read { path, err }),
+        }
+    }
+
+    /// Loads the answers for every year in `years`, once each.
+    pub fn load_years(
+        years: impl IntoIterator<Item = u16>,
+    ) -> Result<BTreeMap<u16, Self>, AnswersError> {
+        let mut answers = BTreeMap::new();
+        for year in years {
+            if let Entry::Vacant(entry) = answers.entry(year) {
+                entry.insert(Self::load(year)?);
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn parse(text: &str, path: &Path) -> Result<Self, AnswersError> {
+        let invalid = |message: String| AnswersError::Invalid {
+            path: path.to_owned(),
+            message,
+        };
+
+        let table: toml::Table = text.parse().map_err(|err| AnswersError::Parse {
+            path: path.to_owned(),
+            err,
+        })?;
+
+        let mut days = BTreeMap::new();
+        for (key, value) in table {
+            let day = u8::from_str_radix(&key, 10)
+                .map_err(|_| invalid(format!("{:?} is not a day", key)))?;
+            let parts = value
+                .as_table()
+                .ok_or_else(|| invalid(format!("day {} should be a table", day)))?;
+
+            let mut answers = [None, None];
+            for (name, value) in parts {
+                let index = match name.as_str() {
+                    "part1" => 0,
+                    "part2" => 1,
+                    other => {
+                        return Err(invalid(format!("day {} has unknown key {:?}", day, other)));
+                    }
+                };
+                answers[index] = Some(match value {
+                    toml::Value::Integer(n) => Answer::from(*n),
+                    toml::Value::String(s) => s.parse().unwrap(),
+                    other => {
+                        return Err(invalid(format!(
+                            "day {} {} should be an integer or string, not {}",
+                            day,
+                            name,
+                            other.type_str()
+                        )));
+                    }
+                });
+            }
+            days.insert(day, answers);
+        }
+
+        Ok(Self { days })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.days.get(&day)?[part_index(part)].as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Result<Answers, AnswersError> {
+        Answers::parse(text, Path::new("answers/2015.toml"))
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers =
+            parse("[7]\npart1 = 956\npart2 = \"-3\"\n\n[11]\npart1 = \"abcdffaa\"\n").unwrap();
+
+        assert_eq!(answers.get(7, Part::One), Some(&Answer::Unsigned(956)));
+        assert_eq!(answers.get(7, Part::Two), Some(&Answer::Signed(-3)));
+        assert_eq!(
+            answers.get(11, Part::One),
+            Some(&Answer::Text(String::from("abcdffaa")))
+        );
+        assert_eq!(answers.get(11, Part::Two), None);
+        assert_eq!(answers.get(1, Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(
+            parse("[x]\npart1 = 1"),
+            Err(AnswersError::Invalid { .. })
+        ));
+        assert!(matches!(
+            parse("[1]\npart3 = 1"),
+            Err(AnswersError::Invalid { .. })
+        ));
+        assert!(matches!(
+            parse("[1]\npart1 = 1.5"),
+            Err(AnswersError::Invalid { .. })
+        ));
+        assert!(matches!(parse("[1"), Err(AnswersError::Parse { .. })));
+    }
+}
@@ -0,0 +1,129 @@
+use std::{path::Path, process::ExitCode, time::Duration};
+
+use aoc_core::{DynSolution, bench::Budget};
+
+use crate::{
+    history::{self, Environment, HISTORY_PATH, Record},
+    table::print_table,
+};
+
+/// Times each stage of a day, prints a summary and records it in the history.
+///
+/// With a `compare` threshold, each stage is also checked against the best
+/// previous median on this machine and input, failing if any got slower by
+/// more than that many percent.
+pub fn bench(
+    solution: &dyn DynSolution,
+    input: Option<&str>,
+    budget: &Budget,
+    compare: Option<f64>,
+) -> ExitCode {
+    let input = match aoc_core::load_input(solution.year(), solution.day(), input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let path = Path::new(HISTORY_PATH);
+    let history = match history::load(path) {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let env = Environment::detect();
+    let results = match solution.bench(&input, budget) {
+        Ok(results) => results,
+        Err(err) => {
+            eprintln!("{} day {}: {}", solution.year(), solution.day(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let records: Vec<Record> = results
+        .iter()
+        .map(|(stage, stats)| {
+            Record::new(solution.year(), solution.day(), &input, *stage, stats, &env)
+        })
+        .collect();
+
+    println!(
+        "{} day {} ({} warm-up, up to {} runs or {:.2?})",
+        solution.year(),
+        solution.day(),
+        budget.warmup,
+        budget.iterations,
+        budget.max_time
+    );
+
+    let stats_row = |record: &Record| {
+        let fmt = |nanos| format!("{:.2?}", Duration::from_nanos(nanos));
+        [
+            record.stage.clone(),
+            fmt(record.mean_ns),
+            fmt(record.median_ns),
+            fmt(record.min_ns),
+            fmt(record.stddev_ns),
+            record.runs.to_string(),
+        ]
+    };
+
+    let mut regressions = 0;
+    match compare {
+        None => {
+            let rows: Vec<[String; 6]> = records.iter().map(stats_row).collect();
+            print_table(["stage", "mean", "median", "min", "stddev", "runs"], &rows);
+        }
+        Some(threshold) => {
+            let rows: Vec<[String; 9]> = records
+                .iter()
+                .map(|record| {
+                    let [stage, mean, median, min, stddev, runs] = stats_row(record);
+                    let (best, change, status) = match history::best(&history, record) {
+                        None => (String::from("-"), String::from("-"), String::from("new")),
+                        Some(best) => {
+                            let change = (record.median_ns as f64 / best as f64 - 1.0) * 100.0;
+                            let status = if history::regressed(best, record.median_ns, threshold) {
+                                regressions += 1;
+                                "SLOWER"
+                            } else {
+                                "ok"
+                            };
+                            (
+                                format!("{:.2?}", Duration::from_nanos(best)),
+                                format!("{:+.1}%", change),
+                                String::from(status),
+                            )
+                        }
+                    };
+                    [stage, mean, median, min, stddev, runs, best, change, status]
+                })
+                .collect();
+            print_table(
+                [
+                    "stage", "mean", "median", "min", "stddev", "runs", "best", "change", "status",
+                ],
+                &rows,
+            );
+        }
+    }
+
+    if let Err(err) = history::append(path, &records) {
+        eprintln!("{}", err);
+        return ExitCode::FAILURE;
+    }
+
+    if regressions > 0 {
+        eprintln!(
+            "{} stage(s) slower than their best by more than {}%",
+            regressions,
+            compare.unwrap_or_default()
+        );
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,55 @@
+use std::process::ExitCode;
+
+use aoc_core::DynSolution;
+
+fn plural(count: usize) -> &'static str {
+    if count == 1 {
+        "assumption"
+    } else {
+        "assumptions"
+    }
+}
+
+/// Checks each day's input against the assumptions its solution makes,
+/// naming every one it breaks.
+pub fn check(solutions: &[&dyn DynSolution], input: Option<&str>) -> ExitCode {
+    let mut failed = false;
+
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let checked = aoc_core::load_input(year, day, input)
+            .map_err(|err| err.to_string())
+            .and_then(|input| solution.check(&input).map_err(|err| err.to_string()));
+
+        match checked {
+            Ok((count, broken)) if broken.is_empty() => {
+                let hold = if count == 1 { "holds" } else { "hold" };
+                println!("{} day {}: {} {} {}", year, day, count, plural(count), hold);
+            }
+            Ok((count, broken)) => {
+                failed = true;
+                println!(
+                    "{} day {}: breaks {} of {} {}",
+                    year,
+                    day,
+                    broken.len(),
+                    count,
+                    plural(count)
+                );
+                for name in broken {
+                    println!("  - {}", name);
+                }
+            }
+            Err(err) => {
+                failed = true;
+                eprintln!("{} day {}: {}", year, day, err);
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt::Display;
+
+use aoc_core::Part;
+use ureq::Agent;
+
+use crate::config::Config;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request so the site's operators know what's calling them.
+pub const USER_AGENT: &str = concat!(
+    "aoc/",
+    env!("CARGO_PKG_VERSION"),
+    " (personal advent of code runner, caches every input it downloads)"
+);
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session cookie was configured.
+    NoSession,
+    /// The site answered 404, usually because the puzzle isn't unlocked yet.
+    NotFound {
+        url: String,
+    },
+    /// The site answered 400, which it does for a missing or expired session.
+    BadSession,
+    Status {
+        url: String,
+        status: u16,
+    },
+    Http(ureq::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSession => write!(
+                f,
+                "no session cookie, set `session` in {} or {}",
+                crate::config::CONFIG_PATH,
+                crate::config::SESSION_VAR
+            ),
+            Self::NotFound { url } => {
+                write!(
+                    f,
+                    "{} was not found, the puzzle may not be unlocked yet",
+                    url
+                )
+            }
+            Self::BadSession => write!(f, "the session cookie was rejected, it may have expired"),
+            Self::Status { url, status } => write!(f, "{} answered with status {}", url, status),
+            Self::Http(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<ureq::Error> for ClientError {
+    fn from(err: ureq::Error) -> Self {
+        Self::Http(err)
+    }
+}
+
+/// Talks to the advent of code site, or anything serving the same paths.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(
+            config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL),
+            config.session.clone(),
+        )
+    }
+
+    fn session(&self) -> Result<String, ClientError> {
+        match &self.session {
+            Some(session) => Ok(format!("session={}", session)),
+            None => Err(ClientError::NoSession),
+        }
+    }
+
+    fn check(url: String, status: u16) -> Result<(), ClientError> {
+        match status {
+            200..=299 => Ok(()),
+            400 => Err(ClientError::BadSession),
+            404 => Err(ClientError::NotFound { url }),
+            _ => Err(ClientError::Status { url, status }),
+        }
+    }
+
+    /// Sends the session cookie if there is one, anything that needs it
+    /// should check [`Self::session`] first.
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        let url = format!("{}{}", self.base_url, path);
+        let mut request = self.agent.get(&url);
+        if let Ok(session) = self.session() {
+            request = request.header("Cookie", session);
+        }
+        let mut response = request.call()?;
+
+        Self::check(url, response.status().as_u16())?;
+        Ok(response.body_mut().read_to_string()?)
+    }
+
+    /// Downloads the puzzle input for a day.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.session()?;
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    /// Downloads the puzzle page for a day, which only shows part 2 with a
+    /// session that has solved part 1.
+    pub fn page(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+
+    /// Posts an answer, returning the page the site replies with.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", self.session()?)
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])?;
+
+        Self::check(url, response.status().as_u16())?;
+        Ok(response.body_mut().read_to_string()?)
+    }
+}
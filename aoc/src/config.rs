@@ -0,0 +1,131 @@
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+/// Per user settings, kept out of the repository alongside the bench history.
+pub const CONFIG_PATH: &str = ".aoc/config.toml";
+
+/// Overrides `session` from the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Overrides `base_url` from the config file.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Read { path: PathBuf, err: io::Error },
+    Parse { path: PathBuf, err: toml::de::Error },
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read { path, err } => write!(f, "unable to read {}: {}", path.display(), err),
+            Self::Parse { path, err } => write!(f, "unable to parse {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Settings for talking to the advent of code site.
+///
+/// ```toml
+/// session = "53616c746564..."
+/// base_url = "https://adventofcode.com"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The value of the `session` cookie from a logged in browser.
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// Loads [`CONFIG_PATH`], treating a missing file as empty, then applies
+    /// any overrides from the environment.
+    pub fn load() -> Result<Self, ConfigError> {
+        let mut config = Self::load_from(Path::new(CONFIG_PATH))?;
+
+        if let Some(session) = env::var(SESSION_VAR).ok().filter(|s| !s.is_empty()) {
+            config.session = Some(session);
+        }
+        if let Some(base_url) = env::var(BASE_URL_VAR).ok().filter(|s| !s.is_empty()) {
+            config.base_url = Some(base_url);
+        }
+
+        Ok(config)
+    }
+
+    fn load_from(path: &Path) -> Result<Self, ConfigError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => {
+                return Err(ConfigError::Read {
+                    path: path.to_owned(),
+                    err,
+                });
+            }
+        };
+
+        let mut config: Self = toml::from_str(&text).map_err(|err| ConfigError::Parse {
+            path: path.to_owned(),
+            err,
+        })?;
+        // NOTE: copying the cookie out of a browser tends to bring whitespace along
+        config.session = config.session.map(|s| s.trim().to_owned());
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-config-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_missing_is_empty() {
+        let dir = scratch("missing");
+        assert_eq!(
+            Config::load_from(&dir.join("config.toml")).unwrap(),
+            Config::default()
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = scratch("load");
+        let path = dir.join("config.toml");
+        fs::write(
+            &path,
+            "session = \" abc123\\n\"\nbase_url = \"http://localhost\"\n",
+        )
+        .unwrap();
+
+        let config = Config::load_from(&path).unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url.as_deref(), Some("http://localhost"));
+
+        fs::write(&path, "cookie = \"abc123\"\n").unwrap();
+        assert!(matches!(
+            Config::load_from(&path),
+            Err(ConfigError::Parse { .. })
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
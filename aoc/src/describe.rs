@@ -0,0 +1,443 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc_core::Part;
+
+use crate::{
+    client::Client,
+    config::Config,
+    html::{self, Token},
+};
+
+/// Directory the puzzle descriptions are written to, laid out as
+/// `<year>/<day>.md` with the extracted examples in `<year>/<day>/`.
+pub const PUZZLES_DIR: &str = "puzzles";
+
+/// An answer highlighted in the puzzle text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub part: Part,
+    /// The example it follows, numbered from 1, or 0 if it comes before any.
+    pub example: usize,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Description {
+    pub markdown: String,
+    /// The contents of every `<pre><code>` block, in order.
+    pub examples: Vec<String>,
+    pub suggestions: Vec<Suggestion>,
+}
+
+/// Converts the puzzle articles of a page, resolving relative links against
+/// `base_url`.
+fn markdown(tokens: &[Token], base_url: &str, out: &mut String) {
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut bold_code = false;
+    let mut links = Vec::new();
+
+    for (i, token) in tokens.iter().enumerate() {
+        match *token {
+            Token::Open { name, attrs } => match name {
+                "h2" => out.push_str("## "),
+                "li" => out.push_str("- "),
+                "pre" => {
+                    in_pre = true;
+                    out.push_str("```\n");
+                }
+                "br" if !in_pre => out.push('\n'),
+                "code" if !in_pre => {
+                    in_code = true;
+                    // NOTE: emphasis inside a code span would show as literal stars
+                    bold_code = matches!(tokens.get(i + 1), Some(Token::Open { name: "em", .. }));
+                    out.push_str(if bold_code { "**`" } else { "`" });
+                }
+                "em" if !in_pre && !in_code => out.push_str("**"),
+                "a" if !in_pre => {
+                    let href = html::attr(attrs, "href").unwrap_or_default();
+                    let href = if href.starts_with('/') {
+                        format!("{}{}", base_url, href)
+                    } else {
+                        href
+                    };
+                    links.push(href);
+                    out.push('[');
+                }
+                _ => {}
+            },
+            Token::Close(name) => match name {
+                "h2" | "p" => out.push_str("\n\n"),
+                "li" => out.push('\n'),
+                "ul" | "ol" => out.push('\n'),
+                "pre" => {
+                    in_pre = false;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                }
+                "code" if !in_pre => {
+                    in_code = false;
+                    out.push_str(if bold_code { "`**" } else { "`" });
+                }
+                "em" if !in_pre && !in_code => out.push_str("**"),
+                "a" if !in_pre => {
+                    let href = links.pop().unwrap_or_default();
+                    write!(out, "]({})", href).unwrap();
+                }
+                _ => {}
+            },
+            Token::Text(text) => {
+                let text = html::decode_entities(text);
+                if in_pre {
+                    out.push_str(&text);
+                } else if !text.trim().is_empty() {
+                    // NOTE: only keep the spaces that separate words on the same line
+                    if text.starts_with(char::is_whitespace) && !out.ends_with(['\n', ' ']) {
+                        out.push(' ');
+                    }
+                    out.push_str(&text.split_whitespace().collect::<Vec<_>>().join(" "));
+                    if text.ends_with(char::is_whitespace) {
+                        out.push(' ');
+                    }
+                } else if !out.is_empty() && !out.ends_with(['\n', ' ']) {
+                    out.push(' ');
+                }
+            }
+        }
+    }
+}
+
+/// Trims the spaces left at the ends of lines and squeezes runs of blank
+/// lines, leaving code blocks alone.
+fn tidy(markdown: &str) -> String {
+    let mut tidied = String::new();
+    let mut in_fence = false;
+    for line in markdown.lines() {
+        if line.trim() == "```" {
+            in_fence = !in_fence;
+        }
+        let line = if in_fence { line } else { line.trim() };
+        if line.is_empty() && !in_fence && (tidied.is_empty() || tidied.ends_with("\n\n")) {
+            continue;
+        }
+        tidied.push_str(line);
+        tidied.push('\n');
+    }
+    while tidied.ends_with("\n\n") {
+        tidied.pop();
+    }
+    tidied
+}
+
+/// Finds the examples and the highlighted answers in one puzzle article.
+fn extract(tokens: &[Token], part: Part, description: &mut Description) {
+    let mut example: Option<String> = None;
+    let mut code = 0;
+    let mut em = 0;
+    let mut answer: Option<String> = None;
+
+    for token in tokens {
+        match *token {
+            Token::Open { name: "pre", .. } => example = Some(String::new()),
+            Token::Close("pre") => {
+                if let Some(mut text) = example.take() {
+                    if !text.ends_with('\n') {
+                        text.push('\n');
+                    }
+                    description.examples.push(text);
+                }
+            }
+            Token::Open { name: "code", .. } => code += 1,
+            Token::Open { name: "em", .. } => em += 1,
+            Token::Close(name @ ("code" | "em")) => {
+                if name == "code" {
+                    code -= 1;
+                } else {
+                    em -= 1;
+                }
+                if let Some(text) = answer.take() {
+                    description.suggestions.push(Suggestion {
+                        part,
+                        example: description.examples.len(),
+                        answer: text.trim().to_owned(),
+                    });
+                }
+            }
+            Token::Text(text) => match &mut example {
+                Some(example) => example.push_str(&html::decode_entities(text)),
+                None if code > 0 && em > 0 => answer
+                    .get_or_insert_default()
+                    .push_str(&html::decode_entities(text)),
+                None => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+impl Description {
+    /// Reads the description out of a puzzle page, the first article being
+    /// part 1 and any second one part 2.
+    pub fn parse(page: &str, base_url: &str) -> Self {
+        let mut description = Self::default();
+        let mut markdown_text = String::new();
+
+        for (i, article) in html::elements(page, "article").into_iter().enumerate() {
+            let part = if i == 0 { Part::One } else { Part::Two };
+            let tokens = html::tokens(article);
+            markdown(&tokens, base_url, &mut markdown_text);
+            extract(&tokens, part, &mut description);
+        }
+
+        description.markdown = tidy(&markdown_text);
+        description
+    }
+
+    /// The suggestions as an `answers.toml` in the format the examples use,
+    /// taking the last answer after each example for each part.
+    pub fn answers_toml(&self, year: u16, day: u8) -> String {
+        let value = |answer: &str| match answer.parse::<i64>() {
+            Ok(n) => n.to_string(),
+            Err(_) => toml::Value::String(answer.to_owned()).to_string(),
+        };
+
+        let mut examples: BTreeMap<usize, [Vec<&str>; 2]> = BTreeMap::new();
+        for suggestion in &self.suggestions {
+            let part = if suggestion.part == Part::One { 0 } else { 1 };
+            examples.entry(suggestion.example).or_default()[part].push(&suggestion.answer);
+        }
+
+        let mut toml = format!(
+            "# Suggested by `aoc describe` from the answers highlighted in the puzzle,\n\
+             # each paired with the example before it. Check them before copying\n\
+             # anything into examples/{}/{}.\n",
+            year, day
+        );
+        for (example, parts) in examples {
+            toml.push('\n');
+            if example == 0 {
+                toml.push_str("# before any example\n");
+            } else {
+                writeln!(toml, "[{}]", example).unwrap();
+            }
+            for (i, answers) in parts.iter().enumerate() {
+                let Some((last, rest)) = answers.split_last() else {
+                    continue;
+                };
+                let rest: Vec<String> = rest.iter().map(|a| value(a)).collect();
+                if !rest.is_empty() {
+                    writeln!(toml, "# also part{} = {}", i + 1, rest.join(", ")).unwrap();
+                }
+                let comment = if example == 0 { "# " } else { "" };
+                writeln!(toml, "{}part{} = {}", comment, i + 1, value(last)).unwrap();
+            }
+        }
+        toml
+    }
+
+    /// Writes the Markdown, the examples and the suggested answers under
+    /// `root`, returning every path written.
+    pub fn save(&self, root: &Path, year: u16, day: u8) -> io::Result<Vec<PathBuf>> {
+        let year_dir = root.join(PUZZLES_DIR).join(year.to_string());
+        let day_dir = year_dir.join(day.to_string());
+        fs::create_dir_all(&day_dir)?;
+
+        let mut written = Vec::new();
+        let mut write = |path: PathBuf, contents: &str| {
+            fs::write(&path, contents)?;
+            written.push(path);
+            io::Result::Ok(())
+        };
+
+        write(year_dir.join(format!("{}.md", day)), &self.markdown)?;
+        for (i, example) in self.examples.iter().enumerate() {
+            write(day_dir.join(format!("{}.txt", i + 1)), example)?;
+        }
+        write(day_dir.join("answers.toml"), &self.answers_toml(year, day))?;
+
+        Ok(written)
+    }
+}
+
+/// Describes a day from its page on the site, or from a saved copy.
+pub fn describe(year: u16, day: u8, page: Option<&str>) -> ExitCode {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let client = Client::from_config(&config);
+
+    let page = match page {
+        Some(path) => {
+            fs::read_to_string(path).map_err(|err| format!("unable to read {}: {}", path, err))
+        }
+        None => client.page(year, day).map_err(|err| err.to_string()),
+    };
+    let page = match page {
+        Ok(page) => page,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let description = Description::parse(&page, client.base_url());
+    if description.markdown.is_empty() {
+        eprintln!("no puzzle description found on the page");
+        return ExitCode::FAILURE;
+    }
+
+    match description.save(Path::new(""), year, day) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("unable to save the description: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::mock::MockServer;
+
+    const PAGE: &str = include_str!("../fixtures/describe/page.html");
+    const BASE_URL: &str = "https://adventofcode.com";
+
+    #[test]
+    fn test_markdown() {
+        let description = Description::parse(PAGE, BASE_URL);
+        assert_eq!(
+            description.markdown,
+            "\
+## --- Day 3: Counting Steps ---
+
+An elf walks a grid following arrows: `^` north, `v` south, `>` east and `<` west. **How many** cells does the elf visit?
+
+For example:
+
+- `>` visits **`2`** cells.
+- `^v^v` visits **`2`** cells, going back and forth.
+
+A longer walk:
+
+```
+^>v<
+^^vv
+```
+
+This one visits **`4`** cells, see the [about page](https://adventofcode.com/2015/about) or [elsewhere](https://example.com/more).
+
+## --- Part Two ---
+
+Now two elves take turns.
+
+Taking turns, the walk above visits **`3`** cells instead.
+
+```
+^v
+```
+
+Which visits **`11`** cells.
+"
+        );
+    }
+
+    #[test]
+    fn test_examples_and_suggestions() {
+        let description = Description::parse(PAGE, BASE_URL);
+        assert_eq!(description.examples, ["^>v<\n^^vv\n", "^v\n"]);
+
+        let suggestion = |part, example, answer: &str| Suggestion {
+            part,
+            example,
+            answer: answer.to_owned(),
+        };
+        assert_eq!(
+            description.suggestions,
+            [
+                suggestion(Part::One, 0, "2"),
+                suggestion(Part::One, 0, "2"),
+                suggestion(Part::One, 1, "4"),
+                suggestion(Part::Two, 1, "3"),
+                suggestion(Part::Two, 2, "11"),
+            ]
+        );
+
+        assert_eq!(
+            description.answers_toml(2015, 3),
+            "\
+# Suggested by `aoc describe` from the answers highlighted in the puzzle,
+# each paired with the example before it. Check them before copying
+# anything into examples/2015/3.
+
+# before any example
+# also part1 = 2
+# part1 = 2
+
+[1]
+part1 = 4
+part2 = 3
+
+[2]
+part2 = 11
+"
+        );
+    }
+
+    #[test]
+    fn test_fetch_and_save() {
+        let server = MockServer::start(|request| match request.path.as_str() {
+            "/2015/day/3" => (200, PAGE.to_owned()),
+            _ => (404, String::new()),
+        });
+        let client = Client::new(&server.url, None);
+        let root = env::temp_dir().join(format!("aoc-describe-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        let page = client.page(2015, 3).unwrap();
+        let description = Description::parse(&page, client.base_url());
+        assert!(
+            description
+                .markdown
+                .contains(&format!("]({}/2015/about)", server.url))
+        );
+
+        let written = description.save(&root, 2015, 3).unwrap();
+        let puzzles = root.join(PUZZLES_DIR);
+        assert_eq!(
+            written,
+            [
+                puzzles.join("2015/3.md"),
+                puzzles.join("2015/3/1.txt"),
+                puzzles.join("2015/3/2.txt"),
+                puzzles.join("2015/3/answers.toml"),
+            ]
+        );
+        assert_eq!(fs::read_to_string(&written[2]).unwrap(), "^v\n");
+        // NOTE: the suggestions must stay loadable by `example_tests!`
+        let answers: toml::Table = fs::read_to_string(&written[3]).unwrap().parse().unwrap();
+        assert_eq!(answers["2"]["part2"].as_integer(), Some(11));
+
+        assert_eq!(server.requests()[0].header("cookie"), None);
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,212 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc_core::input::input_path;
+
+use crate::{
+    client::{Client, ClientError},
+    config::Config,
+};
+
+#[derive(Debug)]
+pub enum FetchError {
+    Client(ClientError),
+    Write { path: PathBuf, err: io::Error },
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Client(err) => write!(f, "{}", err),
+            Self::Write { path, err } => write!(f, "unable to write {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<ClientError> for FetchError {
+    fn from(err: ClientError) -> Self {
+        Self::Client(err)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already on disk, nothing was requested.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Where an input is written before it's renamed into place.
+fn partial_path(path: &Path) -> PathBuf {
+    path.with_extension("txt.part")
+}
+
+/// Makes sure the input for a day is saved under `root`, downloading it only
+/// if it isn't there already.
+pub fn fetch_input(
+    client: &Client,
+    root: &Path,
+    year: u16,
+    day: u8,
+) -> Result<Fetched, FetchError> {
+    let path = input_path(root, year, day);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.input(year, day)?;
+
+    // NOTE: written aside and renamed into place, so a download cut short
+    // never leaves a partial input that looks cached
+    let write = || -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let partial = partial_path(&path);
+        fs::write(&partial, input)?;
+        fs::rename(&partial, &path)
+    };
+    write().map_err(|err| FetchError::Write {
+        path: path.clone(),
+        err,
+    })?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+pub fn fetch(year: u16, day: u8) -> ExitCode {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match fetch_input(&Client::from_config(&config), Path::new(""), year, day) {
+        Ok(Fetched::Cached(path)) => {
+            println!("{} is already downloaded", path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(Fetched::Downloaded(path)) => {
+            println!("saved {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::{client::USER_AGENT, mock::MockServer};
+
+    const SESSION: &str = "0123abcd";
+    const INPUT: &str = include_str!("../../examples/2015/9/1.txt");
+
+    /// Serves 2015 day 9 to the right session, 404s every other day like the
+    /// real site does before a puzzle unlocks, and 400s any other session.
+    fn server() -> MockServer {
+        MockServer::start(|request| {
+            if request.header("cookie") != Some(format!("session={}", SESSION).as_str()) {
+                return (
+                    400,
+                    "Puzzle inputs differ by user.  Please log in.".to_owned(),
+                );
+            }
+            match request.path.as_str() {
+                "/2015/day/9/input" => (200, INPUT.to_owned()),
+                _ => (404, "Not Found".to_owned()),
+            }
+        })
+    }
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_then_cached() {
+        let server = server();
+        let client = Client::new(&server.url, Some(SESSION.to_owned()));
+        let root = scratch("cached");
+        let path = input_path(&root, 2015, 9);
+
+        assert_eq!(
+            fetch_input(&client, &root, 2015, 9).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), INPUT);
+        assert!(!partial_path(&path).exists());
+        assert_eq!(
+            fetch_input(&client, &root, 2015, 9).unwrap(),
+            Fetched::Cached(path)
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].header("user-agent"), Some(USER_AGENT));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_cached_without_session() {
+        let root = scratch("no-session");
+        let path = input_path(&root, 2015, 9);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, INPUT).unwrap();
+
+        let client = Client::new("http://127.0.0.1:1", None);
+        assert_eq!(
+            fetch_input(&client, &root, 2015, 9).unwrap(),
+            Fetched::Cached(path)
+        );
+        assert!(matches!(
+            fetch_input(&client, &root, 2015, 10),
+            Err(FetchError::Client(ClientError::NoSession))
+        ));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_not_unlocked() {
+        let server = server();
+        let client = Client::new(&server.url, Some(SESSION.to_owned()));
+        let root = scratch("locked");
+
+        assert!(matches!(
+            fetch_input(&client, &root, 2015, 25),
+            Err(FetchError::Client(ClientError::NotFound { .. }))
+        ));
+        assert!(!input_path(&root, 2015, 25).exists());
+    }
+
+    #[test]
+    fn test_bad_session() {
+        let server = server();
+        let client = Client::new(&server.url, Some("expired".to_owned()));
+        let root = scratch("bad-session");
+
+        assert!(matches!(
+            fetch_input(&client, &root, 2015, 9),
+            Err(FetchError::Client(ClientError::BadSession))
+        ));
+        assert!(!input_path(&root, 2015, 9).exists());
+    }
+}
@@ -0,0 +1,118 @@
+use std::{
+    fs, panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc_core::DynSolution;
+
+/// How much of a generated input goes in the corpus, since mutating a whole
+/// real sized input is slow and rarely finds more.
+const GENERATED_LINES: usize = 30;
+
+/// Every `.txt` file in `<root>/<kind>/<year>/<day>`, in name order.
+fn inputs(root: &Path, kind: &str, solution: &dyn DynSolution) -> Vec<(PathBuf, String)> {
+    let dir = root.join(format!("{}/{}/{}", kind, solution.year(), solution.day()));
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| fs::read_to_string(&path).ok().map(|text| (path, text)))
+        .collect()
+}
+
+/// Inputs that once crashed the day, kept under `fuzz/<year>/<day>`. Each has
+/// to be turned away by the parser or an assumption before the parts see it.
+fn regressions(root: &Path, solution: &dyn DynSolution) -> Vec<(PathBuf, String)> {
+    inputs(root, "fuzz", solution)
+}
+
+/// The inputs to start mutating from: the day's examples, its regressions,
+/// and the start of a generated input.
+pub fn corpus(root: &Path, solution: &dyn DynSolution) -> Vec<String> {
+    let mut corpus: Vec<String> = inputs(root, "examples", solution)
+        .into_iter()
+        .chain(regressions(root, solution))
+        .map(|(_, text)| text)
+        .collect();
+    if let Some(Ok(input)) = solution.generate(0, 1) {
+        let start: Vec<&str> = input.lines().take(GENERATED_LINES).collect();
+        corpus.push(start.join("\n") + "\n");
+    }
+    corpus
+}
+
+/// Throws mutated inputs at each day's parser, reporting any that make it
+/// panic instead of returning an error.
+pub fn fuzz(root: &Path, solutions: &[&dyn DynSolution], runs: usize, seed: u64) -> ExitCode {
+    let mut failed = false;
+    // NOTE: every caught panic would print a message otherwise
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let corpus = corpus(root, *solution);
+        let parse = |input: &str| {
+            let _ = solution.parse(input);
+        };
+        match aoc_core::fuzz::fuzz(&parse, &corpus, runs, seed) {
+            Ok(()) => println!("{} day {}: no panics in {} runs", year, day, runs),
+            Err(crash) => {
+                failed = true;
+                println!("{} day {}: {}", year, day, crash);
+            }
+        }
+    }
+
+    panic::set_hook(hook);
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::registry::SOLUTIONS;
+
+    #[test]
+    fn test_every_parser_survives() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+        for solution in SOLUTIONS {
+            let corpus = corpus(&root, *solution);
+            assert!(!corpus.is_empty());
+            let parse = |input: &str| {
+                let _ = solution.parse(input);
+            };
+            if let Err(crash) = aoc_core::fuzz::fuzz(&parse, &corpus, 500, 0) {
+                panic!("{} day {}: {}", solution.year(), solution.day(), crash);
+            }
+        }
+    }
+
+    #[test]
+    fn test_regressions_are_refused() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+        let mut seen = 0;
+        for solution in SOLUTIONS {
+            for (path, input) in regressions(&root, *solution) {
+                if let Ok((_, broken)) = solution.check(&input) {
+                    assert!(!broken.is_empty(), "{} was accepted", path.display());
+                }
+                seen += 1;
+            }
+        }
+        assert!(seen > 0);
+    }
+}
@@ -0,0 +1,65 @@
+use std::{
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_core::DynSolution;
+
+/// A seed for when none is given, different on every run.
+pub fn fresh_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_nanos() as u64)
+}
+
+/// Prints a random input for the day, which its parser has already accepted.
+pub fn generate(solution: &dyn DynSolution, seed: u64, scale: usize) -> ExitCode {
+    let (year, day) = (solution.year(), solution.day());
+    match solution.generate(seed, scale) {
+        Some(Ok(input)) => {
+            println!("{}", input.trim_end());
+            ExitCode::SUCCESS
+        }
+        Some(Err(err)) => {
+            eprintln!(
+                "{} day {}: the generator made an input its parser rejects, from seed {} at scale {}\n{}",
+                year, day, seed, scale, err
+            );
+            ExitCode::FAILURE
+        }
+        None => {
+            eprintln!("{} day {} has no generator", year, day);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::SOLUTIONS;
+
+    #[test]
+    fn test_every_day_round_trips() {
+        for solution in SOLUTIONS {
+            let (year, day) = (solution.year(), solution.day());
+            for seed in 0..3 {
+                for scale in 1..=2 {
+                    let input = solution
+                        .generate(seed, scale)
+                        .unwrap_or_else(|| panic!("{} day {} has no generator", year, day))
+                        .unwrap_or_else(|err| {
+                            panic!(
+                                "{} day {}, seed {}, scale {}: {}",
+                                year, day, seed, scale, err
+                            )
+                        });
+                    // NOTE: bigger inputs can outgrow counts the real one happens to have
+                    if scale == 1 {
+                        let (_, broken) = solution.check(&input).unwrap();
+                        assert_eq!(broken, Vec::<&str>::new(), "{} day {}", year, day);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use std::{
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_core::bench::{Stage, Stats};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+/// Where benchmark results are appended, one JSON object per line.
+pub const HISTORY_PATH: &str = ".aoc/bench.jsonl";
+
+#[derive(Debug)]
+pub enum HistoryError {
+    Io {
+        path: PathBuf,
+        err: io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        line: usize,
+        err: serde_json::Error,
+    },
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, err } => write!(f, "{}: {}", path.display(), err),
+            Self::Parse { path, line, err } => {
+                write!(f, "{} line {}: {}", path.display(), line, err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+/// One timed stage of one benchmark run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// `None` when not run inside a git checkout.
+    pub commit: Option<String>,
+    pub machine: String,
+    pub year: u16,
+    pub day: u8,
+    /// From [`input_hash`], so timings of different inputs aren't compared.
+    pub input: String,
+    pub stage: String,
+    pub runs: usize,
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub stddev_ns: u64,
+}
+
+/// A short fingerprint of an input, FNV-1a as it's stable across Rust versions
+/// unlike the std hasher.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+impl Record {
+    pub fn new(
+        year: u16,
+        day: u8,
+        input: &str,
+        stage: Stage,
+        stats: &Stats,
+        env: &Environment,
+    ) -> Self {
+        let nanos = |d: Duration| d.as_nanos() as u64;
+
+        Self {
+            timestamp: env.timestamp,
+            commit: env.commit.clone(),
+            machine: env.machine.clone(),
+            year,
+            day,
+            input: input_hash(input),
+            stage: stage.to_string(),
+            runs: stats.runs,
+            mean_ns: nanos(stats.mean),
+            median_ns: nanos(stats.median),
+            min_ns: nanos(stats.min),
+            stddev_ns: nanos(stats.stddev),
+        }
+    }
+}
+
+/// Where and when a benchmark was run.
+#[derive(Debug, Clone)]
+pub struct Environment {
+    pub timestamp: u64,
+    pub commit: Option<String>,
+    pub machine: String,
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+impl Environment {
+    pub fn detect() -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        // NOTE: uncommitted changes mean the hash alone doesn't describe what was timed
+        let commit = git(&["rev-parse", "--short", "HEAD"]).map(|hash| {
+            match git(&["status", "--porcelain", "--untracked-files=no"]) {
+                Some(status) if !status.is_empty() => format!("{}-dirty", hash),
+                _ => hash,
+            }
+        });
+
+        let machine = env::var("AOC_MACHINE_ID")
+            .ok()
+            .or_else(|| fs::read_to_string("/etc/machine-id").ok())
+            .or_else(|| env::var("HOSTNAME").ok())
+            .map(|id| id.trim().to_owned())
+            .filter(|id| !id.is_empty())
+            .unwrap_or_else(|| String::from("unknown"));
+
+        Self {
+            timestamp,
+            commit,
+            machine,
+        }
+    }
+}
+
+/// Reads every record, treating a missing file as an empty history.
+pub fn load<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>, HistoryError> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(HistoryError::Io {
+                path: path.to_owned(),
+                err,
+            });
+        }
+    };
+
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|err| HistoryError::Parse {
+                path: path.to_owned(),
+                line: i + 1,
+                err,
+            })
+        })
+        .collect()
+}
+
+pub fn append<T: Serialize>(path: &Path, records: &[T]) -> Result<(), HistoryError> {
+    let io_err = |err| HistoryError::Io {
+        path: path.to_owned(),
+        err,
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_err)?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(io_err)?;
+
+    for record in records {
+        let line = serde_json::to_string(record).expect("records always serialise");
+        writeln!(file, "{}", line).map_err(io_err)?;
+    }
+
+    Ok(())
+}
+
+/// The fastest median previously recorded on this machine for the same stage
+/// of the same input.
+pub fn best(history: &[Record], current: &Record) -> Option<u64> {
+    history
+        .iter()
+        .filter(|record| {
+            record.machine == current.machine
+                && record.year == current.year
+                && record.day == current.day
+                && record.input == current.input
+                && record.stage == current.stage
+        })
+        .map(|record| record.median_ns)
+        .min()
+}
+
+/// Whether `current` is slower than `best` by more than `threshold` percent.
+pub fn regressed(best: u64, current: u64, threshold: f64) -> bool {
+    current as f64 > best as f64 * (1.0 + threshold / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(machine: &str, stage: &str, median_ns: u64) -> Record {
+        Record {
+            timestamp: 0,
+            commit: Some(String::from("abc1234")),
+            machine: machine.to_owned(),
+            year: 2025,
+            day: 4,
+            input: input_hash("1\n2\n"),
+            stage: stage.to_owned(),
+            runs: 10,
+            mean_ns: median_ns,
+            median_ns,
+            min_ns: median_ns,
+            stddev_ns: 0,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let path = env::temp_dir().join(format!("aoc-history-{}/bench.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let records = vec![record("a", "parse", 10), record("a", "part 1", 20)];
+
+        append(&path, &records[..1]).unwrap();
+        append(&path, &records[1..]).unwrap();
+        assert_eq!(load::<Record>(&path).unwrap(), records);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_best() {
+        let history = vec![
+            record("a", "part 1", 30),
+            record("a", "part 1", 20),
+            record("b", "part 1", 5),
+            record("a", "part 2", 1),
+        ];
+
+        assert_eq!(best(&history, &record("a", "part 1", 25)), Some(20));
+        assert_eq!(best(&history, &record("c", "part 1", 25)), None);
+
+        let other_input = Record {
+            input: input_hash("3\n"),
+            ..record("a", "part 1", 25)
+        };
+        assert_eq!(best(&history, &other_input), None);
+        assert_ne!(input_hash("1\n2\n"), input_hash("1\n2\n3\n"));
+    }
+
+    #[test]
+    fn test_regressed() {
+        assert!(!regressed(100, 110, 10.0));
+        assert!(regressed(100, 111, 10.0));
+        assert!(!regressed(100, 50, 10.0));
+    }
+}
@@ -0,0 +1,216 @@
+//! Just enough HTML handling for the pages the site serves.
+
+/// The contents of every `<tag>` element in a page, in order.
+///
+/// Elements of the same tag nested in each other aren't supported, the site
+/// doesn't nest the ones we look for.
+pub fn elements<'a>(page: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+
+    let mut found = Vec::new();
+    let mut rest = page;
+    while let Some(start) = rest.find(&open) {
+        rest = &rest[start + open.len()..];
+        // NOTE: `<pre` shouldn't match `<prefix>`
+        if !rest.starts_with(['>', ' ', '\n']) {
+            continue;
+        }
+        let Some(body) = rest.find('>') else { break };
+        rest = &rest[body + 1..];
+        let Some(end) = rest.find(&close) else { break };
+        found.push(&rest[..end]);
+        rest = &rest[end + close.len()..];
+    }
+    found
+}
+
+/// Decodes the named entities the site uses, and numeric ones.
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| &rest[1..end]);
+        let c = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = entity.strip_prefix('#')?;
+                let code = match code.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => u32::from_str_radix(code, 10).ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+
+        match (c, entity) {
+            (Some(c), Some(entity)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Strips every tag, leaving the decoded text.
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    decode_entities(&text)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'a> {
+    /// An opening tag, with its name and raw attributes.
+    Open {
+        name: &'a str,
+        attrs: &'a str,
+    },
+    Close(&'a str),
+    /// Text between tags, still holding its entities.
+    Text(&'a str),
+}
+
+/// Splits a fragment into tags and text, dropping comments.
+pub fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        let tag = rest[1..end].trim_end_matches('/').trim();
+        rest = &rest[end + 1..];
+
+        match tag.strip_prefix('/') {
+            Some(name) => tokens.push(Token::Close(name.trim())),
+            None => {
+                let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                tokens.push(Token::Open { name, attrs });
+            }
+        }
+    }
+    tokens
+}
+
+/// The decoded value of a `name="value"` attribute.
+pub fn attr(attrs: &str, name: &str) -> Option<String> {
+    let mut rest = attrs;
+    loop {
+        let start = rest.find(name)?;
+        let preceded = rest[..start]
+            .chars()
+            .next_back()
+            .is_none_or(char::is_whitespace);
+        rest = &rest[start + name.len()..];
+        if !preceded {
+            continue;
+        }
+        let Some(value) = rest.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        let quote = value.chars().next()?;
+        let value = if quote == '"' || quote == '\'' {
+            let value = &value[1..];
+            &value[..value.find(quote)?]
+        } else {
+            value.split_whitespace().next()?
+        };
+        return Some(decode_entities(value));
+    }
+}
+
+/// The readable text of a fragment, with runs of whitespace collapsed.
+pub fn text(html: &str) -> String {
+    strip_tags(html)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elements() {
+        let page = "<pre>no</pre><p>a <em>b</em></p><prefix>no</prefix><p class=\"x\">c</p>";
+        assert_eq!(elements(page, "p"), ["a <em>b</em>", "c"]);
+        assert_eq!(elements(page, "em"), ["b"]);
+        assert!(elements(page, "article").is_empty());
+    }
+
+    #[test]
+    fn test_tokens() {
+        assert_eq!(
+            tokens("<p class=\"x\">a<!-- hi -->&amp;<br/></p>b"),
+            [
+                Token::Open {
+                    name: "p",
+                    attrs: "class=\"x\""
+                },
+                Token::Text("a"),
+                Token::Text("&amp;"),
+                Token::Open {
+                    name: "br",
+                    attrs: ""
+                },
+                Token::Close("p"),
+                Token::Text("b"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_attr() {
+        let attrs = "data-href=\"no\" href=\"/2015/day/3?a=1&amp;b=2\" target=_blank";
+        assert_eq!(attr(attrs, "href").as_deref(), Some("/2015/day/3?a=1&b=2"));
+        assert_eq!(attr(attrs, "target").as_deref(), Some("_blank"));
+        assert_eq!(attr(attrs, "title"), None);
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            text("<p>You don&apos;t  seem\n<a href=\"/\">to &lt;be&gt;</a> &#65;&#x42; & co</p>"),
+            "You don't seem to <be> AB & co"
+        );
+    }
+}
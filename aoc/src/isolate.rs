@@ -0,0 +1,388 @@
+//! Runs each part of each day in a process of its own, several at once, so a
+//! part that hangs, panics or eats all the memory can't take the rest down.
+//!
+//! The runner starts itself again with the hidden `worker` command, hands it
+//! the input on stdin and reads a [`Report`] back from its stdout.
+
+use std::{
+    env,
+    io::{self, Read, Write},
+    path::Path,
+    process::{Child, Command, ExitCode, ExitStatus, Stdio},
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use aoc_core::{Answer, DynSolution, Part, Timed};
+use serde::{Deserialize, Serialize};
+
+use crate::report::{Failure, PartOutcome};
+
+/// How often a running worker is checked on.
+const POLL: Duration = Duration::from_millis(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Wall clock time allowed for each part, parsing included.
+    pub timeout: Duration,
+    /// Memory allowed for each part, in MiB.
+    pub memory: Option<u64>,
+    /// How many workers run at once.
+    pub jobs: usize,
+}
+
+/// What a worker prints when it's done.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+enum Report {
+    Solved {
+        parse_ns: u64,
+        answer: String,
+        elapsed_ns: u64,
+    },
+    Failed {
+        error: String,
+    },
+}
+
+#[cfg(unix)]
+fn limit_memory(bytes: u64) -> io::Result<()> {
+    let limit = libc::rlimit {
+        rlim_cur: bytes as libc::rlim_t,
+        rlim_max: bytes as libc::rlim_t,
+    };
+    // SAFETY: setrlimit only reads the limit it's given
+    match unsafe { libc::setrlimit(libc::RLIMIT_DATA, &limit) } {
+        0 => Ok(()),
+        _ => Err(io::Error::last_os_error()),
+    }
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_: u64) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "memory limits need a unix system",
+    ))
+}
+
+/// The `worker` command: solves one part of the input on stdin and prints a
+/// [`Report`].
+pub fn worker(solution: &dyn DynSolution, part: Part, memory: Option<u64>) -> ExitCode {
+    let mut input = String::new();
+    if let Err(err) = io::stdin().read_to_string(&mut input) {
+        eprintln!("unable to read the input: {}", err);
+        return ExitCode::FAILURE;
+    }
+    if let Some(mib) = memory
+        && let Err(err) = limit_memory(mib * 1024 * 1024)
+    {
+        eprintln!("unable to limit memory: {}", err);
+        return ExitCode::FAILURE;
+    }
+
+    let report = match solution.solve(&input, Some(part)) {
+        Ok(result) => {
+            let (_, timed) = &result.parts[0];
+            Report::Solved {
+                parse_ns: result.parse.as_nanos() as u64,
+                answer: timed.answer.to_string(),
+                elapsed_ns: timed.elapsed.as_nanos() as u64,
+            }
+        }
+        Err(err) => Report::Failed {
+            error: err.to_string(),
+        },
+    };
+    match serde_json::to_writer(io::stdout().lock(), &report) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("unable to write the report: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The message of a panic, from what the default hook wrote to stderr.
+fn panic_message(stderr: &str) -> String {
+    const MARKER: &str = "panicked at ";
+    let Some(start) = stderr.find(MARKER) else {
+        return stderr.trim().to_owned();
+    };
+    let rest = &stderr[start + MARKER.len()..];
+    let (location, rest) = rest.split_once(":\n").unwrap_or((rest.trim(), ""));
+    let message: Vec<&str> = rest
+        .lines()
+        .take_while(|line| !line.starts_with("note:") && !line.starts_with("stack backtrace:"))
+        .collect();
+    format!("{} at {}", message.join(" "), location)
+}
+
+/// Works out how a worker went from how it exited and what it printed.
+fn classify(status: ExitStatus, stdout: &str, stderr: &str) -> Result<(Duration, Timed), Failure> {
+    if status.success() {
+        return match serde_json::from_str(stdout) {
+            Ok(Report::Solved {
+                parse_ns,
+                answer,
+                elapsed_ns,
+            }) => Ok((
+                Duration::from_nanos(parse_ns),
+                Timed {
+                    answer: answer.parse::<Answer>().unwrap(),
+                    elapsed: Duration::from_nanos(elapsed_ns),
+                    alloc: None,
+                },
+            )),
+            Ok(Report::Failed { error }) => Err(Failure::Parse(error)),
+            Err(err) => Err(Failure::Crashed(format!("unreadable report: {}", err))),
+        };
+    }
+
+    // NOTE: failing to allocate aborts rather than panics
+    if stderr.contains("memory allocation of") {
+        Err(Failure::OutOfMemory)
+    } else if stderr.contains("panicked at ") {
+        Err(Failure::Panicked(panic_message(stderr)))
+    } else {
+        let last = stderr.lines().next_back().unwrap_or_default();
+        Err(Failure::Crashed(
+            format!("{} {}", status, last).trim().to_owned(),
+        ))
+    }
+}
+
+fn read_all(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut text = String::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_string(&mut text);
+        }
+        text
+    })
+}
+
+/// Waits for `child` until the deadline, killing it if it's still going.
+fn wait(child: &mut Child, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(POLL);
+    }
+}
+
+struct Job<'a> {
+    year: u16,
+    day: u8,
+    part: Part,
+    input: &'a str,
+}
+
+fn run_job(exe: &Path, job: &Job, limits: &Limits) -> Result<(Duration, Timed), Failure> {
+    let crashed = |err: io::Error| Failure::Crashed(err.to_string());
+
+    let mut command = Command::new(exe);
+    command
+        .arg("worker")
+        .args([
+            job.year.to_string(),
+            job.day.to_string(),
+            u8::from(job.part).to_string(),
+        ])
+        .env("RUST_BACKTRACE", "0")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(memory) = limits.memory {
+        command.args(["--memory", &memory.to_string()]);
+    }
+    let mut child = command.spawn().map_err(crashed)?;
+
+    // NOTE: the pipes are handled on other threads so a full one can't stall the wait
+    let stdin = child.stdin.take();
+    let input = job.input.to_owned();
+    thread::spawn(move || {
+        if let Some(mut stdin) = stdin {
+            let _ = stdin.write_all(input.as_bytes());
+        }
+    });
+    let stdout = read_all(child.stdout.take());
+    let stderr = read_all(child.stderr.take());
+
+    let Some(status) = wait(&mut child, limits.timeout).map_err(crashed)? else {
+        return Err(Failure::TimedOut(limits.timeout));
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    classify(status, &stdout, &stderr)
+}
+
+/// Solves `parts` of every day in `solutions`, each in its own worker, and
+/// returns the outcomes in day then part order.
+pub fn solve(
+    solutions: &[&dyn DynSolution],
+    parts: &[Part],
+    input: Option<&str>,
+    limits: &Limits,
+) -> Vec<PartOutcome> {
+    let exe = env::current_exe();
+    let inputs: Vec<Result<String, Failure>> = solutions
+        .iter()
+        .map(|solution| {
+            aoc_core::load_input(solution.year(), solution.day(), input)
+                .map_err(|err| Failure::NoInput(err.to_string()))
+        })
+        .collect();
+
+    let mut outcomes = Vec::new();
+    let mut jobs = Vec::new();
+    for (solution, input) in solutions.iter().zip(&inputs) {
+        for &part in parts {
+            let (year, day) = (solution.year(), solution.day());
+            let result = match (input, &exe) {
+                (Err(failure), _) => Err(failure.clone()),
+                (Ok(_), Err(err)) => Err(Failure::Crashed(format!(
+                    "unable to find the runner to start: {}",
+                    err
+                ))),
+                (Ok(input), Ok(_)) => {
+                    jobs.push((
+                        outcomes.len(),
+                        Job {
+                            year,
+                            day,
+                            part,
+                            input,
+                        },
+                    ));
+                    // NOTE: replaced once the job runs, so this only shows if a thread died
+                    Err(Failure::Crashed(String::from("never ran")))
+                }
+            };
+            outcomes.push(PartOutcome {
+                year,
+                day,
+                part,
+                result,
+            });
+        }
+    }
+
+    let Ok(exe) = exe else {
+        return outcomes;
+    };
+    let next = AtomicUsize::new(0);
+    let outcomes = Mutex::new(outcomes);
+    thread::scope(|scope| {
+        for _ in 0..limits.jobs.max(1) {
+            scope.spawn(|| {
+                while let Some((index, job)) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = run_job(&exe, job, limits);
+                    outcomes.lock().unwrap()[*index].result = result;
+                }
+            });
+        }
+    });
+    outcomes.into_inner().unwrap()
+}
+
+/// Prints the outcomes a day at a time, as `aoc run` does.
+pub fn print(outcomes: &[PartOutcome]) {
+    for day in outcomes.chunk_by(|a, b| (a.year, a.day) == (b.year, b.day)) {
+        println!("{} day {}", day[0].year, day[0].day);
+        for outcome in day {
+            match &outcome.result {
+                Ok((parse, timed)) => println!(
+                    "  part {}: {} ({:.2?}, parsed in {:.2?})",
+                    outcome.part, timed.answer, timed.elapsed, parse
+                ),
+                Err(failure) => println!("  part {}: {}", outcome.part, failure),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    fn exit(code: i32) -> ExitStatus {
+        use std::os::unix::process::ExitStatusExt;
+        ExitStatus::from_raw(code << 8)
+    }
+
+    /// Runs 2015 day 1 past its assumptions, for [`test_panic_message`] to watch panic.
+    #[test]
+    #[ignore = "panics on purpose"]
+    fn panicking_worker() {
+        use aoc_core::{Input, Solution};
+        use y2015_d1::Day1;
+
+        let parsed = Day1::parse(&Input::new("(")).unwrap();
+        Day1::part2(&parsed);
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let output = Command::new(env::current_exe().unwrap())
+            .args([
+                "isolate::tests::panicking_worker",
+                "--exact",
+                "--ignored",
+                "--nocapture",
+            ])
+            .env("RUST_BACKTRACE", "0")
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+
+        let message = panic_message(&stderr);
+        let (message, location) = message.split_once(" at ").unwrap();
+        assert_eq!(message, "santa never enters the basement");
+        assert!(location.starts_with("y2015/d1/src/lib.rs:"), "{}", location);
+        assert!(matches!(
+            classify(output.status, "", &stderr),
+            Err(Failure::Panicked(_))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_classify() {
+        let report = serde_json::to_string(&Report::Solved {
+            parse_ns: 10,
+            answer: String::from("956"),
+            elapsed_ns: 20,
+        })
+        .unwrap();
+        let (parse, timed) = classify(exit(0), &report, "").unwrap();
+        assert_eq!(parse, Duration::from_nanos(10));
+        assert_eq!(timed.answer, Answer::Unsigned(956));
+
+        let report = r#"{"outcome":"failed","error":"expected a number"}"#;
+        assert_eq!(
+            classify(exit(0), report, "").unwrap_err(),
+            Failure::Parse(String::from("expected a number"))
+        );
+        assert!(matches!(
+            classify(exit(101), "", "thread 'main' panicked at src/lib.rs:1:1:\noh no\n"),
+            Err(Failure::Panicked(message)) if message == "oh no at src/lib.rs:1:1"
+        ));
+        assert_eq!(
+            classify(exit(134), "", "memory allocation of 1024 bytes failed\n").unwrap_err(),
+            Failure::OutOfMemory
+        );
+    }
+}
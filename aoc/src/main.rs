@@ -0,0 +1,504 @@
+use std::{
+    io,
+    path::Path,
+    process::ExitCode,
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{DayResult, DynSolution, Part, alloc::AllocStats, bench::Budget};
+use clap::{Parser, Subcommand};
+
+use crate::{
+    answers::Answers,
+    isolate::Limits,
+    report::{Failure, Format, PartOutcome},
+};
+
+mod answers;
+mod bench;
+mod check;
+mod client;
+mod config;
+mod describe;
+mod fetch;
+mod fuzz;
+mod generate;
+mod history;
+mod html;
+mod isolate;
+#[cfg(test)]
+mod mock;
+mod registry;
+mod report;
+mod scaffold;
+mod stars;
+mod submit;
+mod table;
+mod verify;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOC: aoc_core::alloc::CountingAlloc = aoc_core::alloc::CountingAlloc;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Runs advent of code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Show debug output from the days, or trace output with `-vv`
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one day, or every day in a year with `--all`
+    Run {
+        year: u16,
+        day: Option<u8>,
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Solve every day in the year
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        /// Read the input from this file, or stdin for `-`
+        #[arg(long, conflicts_with = "all")]
+        input: Option<String>,
+        /// How to print the results
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+        /// Solve each part in its own process, several at once, so one that
+        /// hangs or crashes doesn't stop the rest
+        #[arg(long)]
+        isolate: bool,
+        /// Give up on a part after this many seconds
+        #[arg(long, default_value_t = 60.0, requires = "isolate")]
+        timeout: f64,
+        /// Limit each part to this many MiB of memory, on unix
+        #[arg(long, requires = "isolate")]
+        memory: Option<u64>,
+        /// How many parts to solve at once, one per core by default
+        #[arg(long, requires = "isolate")]
+        jobs: Option<usize>,
+    },
+    /// Time parsing and each part of a day over many runs
+    Bench {
+        year: u16,
+        day: u8,
+        /// The most timed runs to take of each stage
+        #[arg(long, default_value_t = Budget::default().iterations)]
+        iterations: usize,
+        /// Untimed runs before timing starts
+        #[arg(long, default_value_t = Budget::default().warmup)]
+        warmup: usize,
+        /// Stop a stage early after this many seconds
+        #[arg(long, default_value_t = Budget::default().max_time.as_secs_f64())]
+        max_time: f64,
+        /// Read the input from this file, or stdin for `-`
+        #[arg(long)]
+        input: Option<String>,
+        /// Flag stages slower than their best recorded median on the same input
+        #[arg(long)]
+        compare: bool,
+        /// How many percent slower than the best counts as a regression
+        #[arg(long, default_value_t = 10.0, requires = "compare")]
+        threshold: f64,
+    },
+    /// Check a day's input against what its solution assumes about it
+    Check {
+        year: u16,
+        day: Option<u8>,
+        /// Read the input from this file, or stdin for `-`
+        #[arg(long, requires = "day")]
+        input: Option<String>,
+    },
+    /// Print a random input for a day, to test or time it beyond the real one
+    Gen {
+        year: u16,
+        day: u8,
+        /// Start from this seed, so the same input can be made again
+        #[arg(long)]
+        seed: Option<u64>,
+        /// How much bigger than a real input to make it, roughly
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        scale: u64,
+    },
+    /// Feed each day's parser mutated inputs, looking for any that make it
+    /// panic instead of returning an error
+    Fuzz {
+        year: u16,
+        day: Option<u8>,
+        /// How many inputs to try on each parser
+        #[arg(long, default_value_t = 100_000)]
+        runs: usize,
+        /// Start from this seed, so a crash can be found again
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Check solutions against the accepted answers in `answers/<year>.toml`
+    Verify {
+        year: Option<u16>,
+        #[arg(requires = "year")]
+        day: Option<u8>,
+    },
+    /// Download a day's input into `inputs/`, unless it's already there
+    Fetch { year: u16, day: u8 },
+    /// Save a day's puzzle as Markdown in `puzzles/`, with its examples
+    Describe {
+        year: u16,
+        day: u8,
+        /// Read a saved copy of the puzzle page instead of downloading it
+        #[arg(long)]
+        page: Option<String>,
+    },
+    /// Create and register the crate for a new day
+    New {
+        #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Download the day's input too
+        #[arg(long)]
+        fetch: bool,
+    },
+    /// Draw a calendar of the stars earned, from the accepted answers
+    Stars {
+        year: Option<u16>,
+        /// Write it into the README between the `stars` markers
+        #[arg(long)]
+        write: bool,
+    },
+    /// Solve one part of the input on stdin for `run --isolate`
+    #[command(hide = true)]
+    Worker {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(long)]
+        memory: Option<u64>,
+    },
+    /// Submit a part's answer, unless earlier attempts already rule it out
+    Submit {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Read the input from this file, or stdin for `-`
+        #[arg(long)]
+        input: Option<String>,
+    },
+}
+
+fn with_alloc(elapsed: Duration, alloc: Option<AllocStats>) -> String {
+    match alloc {
+        Some(alloc) => format!("{:.2?}, {}", elapsed, alloc),
+        None => format!("{:.2?}", elapsed),
+    }
+}
+
+fn print_result(result: &DayResult) {
+    println!(
+        "{} day {} (parsed in {})",
+        result.year,
+        result.day,
+        with_alloc(result.parse, result.parse_alloc)
+    );
+    for (part, timed) in &result.parts {
+        println!(
+            "  part {}: {} ({})",
+            part,
+            timed.answer,
+            with_alloc(timed.elapsed, timed.alloc)
+        );
+    }
+}
+
+/// Solves each day in this process, printing the results as it goes when
+/// they're wanted as a table.
+fn solve_in_process(
+    solutions: &[&dyn DynSolution],
+    parts: &[Part],
+    input: Option<&str>,
+    print: bool,
+) -> Vec<PartOutcome> {
+    let part = match parts {
+        [part] => Some(*part),
+        _ => None,
+    };
+    let mut total = Duration::ZERO;
+    let mut outcomes = Vec::new();
+
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let outcome = match aoc_core::load_input(year, day, input) {
+            Ok(input) => solution
+                .solve(&input, part)
+                .map_err(|err| Failure::Parse(err.to_string())),
+            Err(err) => Err(Failure::NoInput(err.to_string())),
+        };
+
+        if print {
+            match &outcome {
+                Ok(result) => {
+                    total += result.parse
+                        + result
+                            .parts
+                            .iter()
+                            .map(|(_, t)| t.elapsed)
+                            .sum::<Duration>();
+                    print_result(result);
+                }
+                Err(Failure::NoInput(err)) => eprintln!("{}", err),
+                Err(failure) => eprintln!("{} day {}: {}", year, day, failure),
+            }
+        }
+        outcomes.extend(report::split(year, day, parts, outcome));
+    }
+
+    if print && solutions.len() > 1 {
+        println!("total: {:.2?}", total);
+    }
+    outcomes
+}
+
+fn run(
+    solutions: &[&dyn DynSolution],
+    part: Option<Part>,
+    input: Option<&str>,
+    format: Format,
+    limits: Option<Limits>,
+) -> ExitCode {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let outcomes = match limits {
+        None => solve_in_process(solutions, &parts, input, format == Format::Table),
+        Some(limits) => {
+            let start = Instant::now();
+            let outcomes = isolate::solve(solutions, &parts, input, &limits);
+            if format == Format::Table {
+                isolate::print(&outcomes);
+                println!("finished in {:.2?}", start.elapsed());
+            }
+            outcomes
+        }
+    };
+
+    if format != Format::Table {
+        let answers = match Answers::load_years(outcomes.iter().map(|o| o.year)) {
+            Ok(answers) => answers,
+            Err(err) => {
+                eprintln!("{}", err);
+                return ExitCode::FAILURE;
+            }
+        };
+        let rows = report::rows(&outcomes, &answers);
+        let written = match format {
+            Format::Json => report::write_json(&rows, io::stdout().lock()),
+            _ => report::write_csv(&rows, io::stdout().lock()),
+        };
+        if let Err(err) = written {
+            eprintln!("unable to write the report: {}", err);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if outcomes.iter().any(|outcome| outcome.result.is_err()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Err(err) = aoc_core::logging::init(cli.verbose) {
+        eprintln!("{}", err);
+        return ExitCode::FAILURE;
+    }
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            all,
+            input,
+            format,
+            isolate,
+            timeout,
+            memory,
+            jobs,
+        } => {
+            if day.is_none() && !all {
+                eprintln!("either give a day or pass --all");
+                return ExitCode::FAILURE;
+            }
+
+            let solutions: Vec<&dyn DynSolution> = registry::find(Some(year), day).collect();
+            if solutions.is_empty() {
+                match day {
+                    Some(day) => eprintln!("no solution for {} day {}", year, day),
+                    None => eprintln!("no solutions for {}", year),
+                }
+                return ExitCode::FAILURE;
+            }
+
+            let part = part.map(|part| if part == 1 { Part::One } else { Part::Two });
+
+            let limits = if isolate {
+                let Ok(timeout) = Duration::try_from_secs_f64(timeout) else {
+                    eprintln!("--timeout must be a positive number of seconds");
+                    return ExitCode::FAILURE;
+                };
+                let jobs = jobs.unwrap_or_else(|| {
+                    thread::available_parallelism().map_or(1, |cores| cores.get())
+                });
+                Some(Limits {
+                    timeout,
+                    memory,
+                    jobs,
+                })
+            } else {
+                None
+            };
+
+            run(&solutions, part, input.as_deref(), format, limits)
+        }
+        Command::Bench {
+            year,
+            day,
+            iterations,
+            warmup,
+            max_time,
+            input,
+            compare,
+            threshold,
+        } => {
+            let Some(solution) = registry::find(Some(year), Some(day)).next() else {
+                eprintln!("no solution for {} day {}", year, day);
+                return ExitCode::FAILURE;
+            };
+            let Ok(max_time) = Duration::try_from_secs_f64(max_time) else {
+                eprintln!("--max-time must be a positive number of seconds");
+                return ExitCode::FAILURE;
+            };
+            let budget = Budget {
+                warmup,
+                iterations,
+                max_time,
+            };
+
+            bench::bench(
+                solution,
+                input.as_deref(),
+                &budget,
+                compare.then_some(threshold),
+            )
+        }
+        Command::Worker {
+            year,
+            day,
+            part,
+            memory,
+        } => {
+            let Some(solution) = registry::find(Some(year), Some(day)).next() else {
+                eprintln!("no solution for {} day {}", year, day);
+                return ExitCode::FAILURE;
+            };
+            let part = if part == 1 { Part::One } else { Part::Two };
+            isolate::worker(solution, part, memory)
+        }
+        Command::Stars { year, write } => {
+            let solutions: Vec<&dyn DynSolution> = registry::find(year, None).collect();
+            stars::stars(&solutions, write)
+        }
+        Command::Check { year, day, input } => {
+            let solutions: Vec<&dyn DynSolution> = registry::find(Some(year), day).collect();
+            if solutions.is_empty() {
+                eprintln!("no solutions for {}", year);
+                return ExitCode::FAILURE;
+            }
+            check::check(&solutions, input.as_deref())
+        }
+        Command::Gen {
+            year,
+            day,
+            seed,
+            scale,
+        } => {
+            let Some(solution) = registry::find(Some(year), Some(day)).next() else {
+                eprintln!("no solution for {} day {}", year, day);
+                return ExitCode::FAILURE;
+            };
+            let seed = seed.unwrap_or_else(|| {
+                let seed = generate::fresh_seed();
+                eprintln!("seed {}", seed);
+                seed
+            });
+            generate::generate(solution, seed, scale as usize)
+        }
+        Command::Fuzz {
+            year,
+            day,
+            runs,
+            seed,
+        } => {
+            let solutions: Vec<&dyn DynSolution> = registry::find(Some(year), day).collect();
+            if solutions.is_empty() {
+                eprintln!("no solutions for {}", year);
+                return ExitCode::FAILURE;
+            }
+            let seed = seed.unwrap_or_else(|| {
+                let seed = generate::fresh_seed();
+                eprintln!("seed {}", seed);
+                seed
+            });
+            fuzz::fuzz(Path::new(""), &solutions, runs, seed)
+        }
+        Command::Verify { year, day } => {
+            let solutions: Vec<&dyn DynSolution> = registry::find(year, day).collect();
+            verify::verify(&solutions)
+        }
+        Command::Fetch { year, day } => fetch::fetch(year, day),
+        Command::Describe { year, day, page } => describe::describe(year, day, page.as_deref()),
+        Command::New { year, day, fetch } => {
+            match scaffold::scaffold(Path::new(""), year, day) {
+                Ok(written) => {
+                    for path in written {
+                        println!("wrote {}", path.display());
+                    }
+                }
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            }
+
+            if fetch {
+                fetch::fetch(year, day)
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            input,
+        } => {
+            let Some(solution) = registry::find(Some(year), Some(day)).next() else {
+                eprintln!("no solution for {} day {}", year, day);
+                return ExitCode::FAILURE;
+            };
+            let part = if part == 1 { Part::One } else { Part::Two };
+
+            submit::submit(solution, part, input.as_deref())
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use aoc_core::{DynSolution, Erased};
+
+/// Every day the runner knows about, in year then day order.
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    // days:start
+    &Erased::<y2015_d1::Day1>::new(),
+    &Erased::<y2015_d2::Day2>::new(),
+    &Erased::<y2015_d3::Day3>::new(),
+    &Erased::<y2015_d4::Day4>::new(),
+    &Erased::<y2015_d5::Day5>::new(),
+    &Erased::<y2015_d6::Day6>::new(),
+    &Erased::<y2015_d7::Day7>::new(),
+    &Erased::<y2015_d8::Day8>::new(),
+    &Erased::<y2015_d9::Day9>::new(),
+    &Erased::<y2015_d10::Day10>::new(),
+    &Erased::<y2015_d11::Day11>::new(),
+    &Erased::<y2015_d12::Day12>::new(),
+    &Erased::<y2015_d13::Day13>::new(),
+    &Erased::<y2015_d14::Day14>::new(),
+    &Erased::<y2015_d15::Day15>::new(),
+    &Erased::<y2025_d1::Day1>::new(),
+    &Erased::<y2025_d2::Day2>::new(),
+    &Erased::<y2025_d3::Day3>::new(),
+    &Erased::<y2025_d4::Day4>::new(),
+    &Erased::<y2025_d5::Day5>::new(),
+    &Erased::<y2025_d6::Day6>::new(),
+    &Erased::<y2025_d7::Day7>::new(),
+    &Erased::<y2025_d8::Day8>::new(),
+    &Erased::<y2025_d9::Day9>::new(),
+    &Erased::<y2025_d10::Day10>::new(),
+    &Erased::<y2025_d11::Day11>::new(),
+    &Erased::<y2025_d12::Day12>::new(),
+    // days:end
+];
+
+/// Finds the days matching `year` and `day`, where `None` matches anything.
+pub fn find(year: Option<u16>, day: Option<u8>) -> impl Iterator<Item = &'static dyn DynSolution> {
+    SOLUTIONS.iter().copied().filter(move |s| {
+        year.is_none_or(|year| s.year() == year) && day.is_none_or(|day| s.day() == day)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_and_unique() {
+        let keys: Vec<(u16, u8)> = SOLUTIONS.iter().map(|s| (s.year(), s.day())).collect();
+
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(Some(2015), Some(7)).count(), 1);
+        assert_eq!(find(Some(2025), None).count(), 12);
+        assert_eq!(find(Some(1999), None).count(), 0);
+        assert_eq!(find(None, None).count(), SOLUTIONS.len());
+    }
+}
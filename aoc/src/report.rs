@@ -0,0 +1,281 @@
+//! Machine readable results from `aoc run`, for dashboards and spreadsheets.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    io::{self, Write},
+    time::Duration,
+};
+
+use aoc_core::{DayResult, Part, Timed};
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    answers::Answers,
+    verify::{Status, check},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// Readable output, as it's printed while running
+    #[default]
+    Table,
+    /// An array with an object for each part
+    Json,
+    /// A header, then a line for each part
+    Csv,
+}
+
+/// The outcome of a single part.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// The accepted answer, if one is recorded.
+    pub expected: Option<String>,
+    pub status: Status,
+    /// Shared by both parts, since a day is parsed once.
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// The CSV columns, in the order of the fields of [`Row`].
+const COLUMNS: [&str; 9] = [
+    "year", "day", "part", "answer", "expected", "status", "parse_ns", "solve_ns", "error",
+];
+
+/// Why a part has no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    NoInput(String),
+    /// The input was there but didn't parse.
+    Parse(String),
+    TimedOut(Duration),
+    Panicked(String),
+    OutOfMemory,
+    Crashed(String),
+}
+
+impl Failure {
+    pub fn status(&self) -> Status {
+        match self {
+            Self::NoInput(_) => Status::NoInput,
+            Self::Parse(_) => Status::Fail,
+            Self::TimedOut(_) => Status::TimedOut,
+            Self::Panicked(_) => Status::Panicked,
+            Self::OutOfMemory => Status::OutOfMemory,
+            Self::Crashed(_) => Status::Crashed,
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoInput(err) | Self::Parse(err) => write!(f, "{}", err),
+            Self::TimedOut(after) => write!(f, "timed out after {:.2?}", after),
+            Self::Panicked(message) => write!(f, "panicked: {}", message),
+            Self::OutOfMemory => write!(f, "ran out of memory"),
+            Self::Crashed(message) => write!(f, "crashed: {}", message),
+        }
+    }
+}
+
+/// How a single part went, with how long its day took to parse.
+#[derive(Debug, Clone)]
+pub struct PartOutcome {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub result: Result<(Duration, Timed), Failure>,
+}
+
+/// Splits the outcome of solving a whole day into one for each of `parts`.
+pub fn split(
+    year: u16,
+    day: u8,
+    parts: &[Part],
+    outcome: Result<DayResult, Failure>,
+) -> Vec<PartOutcome> {
+    parts
+        .iter()
+        .map(|&part| {
+            let result = match &outcome {
+                Ok(result) => result
+                    .parts
+                    .iter()
+                    .find(|(p, _)| *p == part)
+                    .map(|(_, timed)| (result.parse, timed.clone()))
+                    .ok_or_else(|| Failure::Crashed(format!("part {} wasn't solved", part))),
+                Err(failure) => Err(failure.clone()),
+            };
+            PartOutcome {
+                year,
+                day,
+                part,
+                result,
+            }
+        })
+        .collect()
+}
+
+/// A row for each outcome, checked against the accepted answers for its year.
+pub fn rows(outcomes: &[PartOutcome], answers: &BTreeMap<u16, Answers>) -> Vec<Row> {
+    outcomes
+        .iter()
+        .map(|outcome| {
+            let expected = answers
+                .get(&outcome.year)
+                .and_then(|answers| answers.get(outcome.day, outcome.part));
+            let mut row = Row {
+                year: outcome.year,
+                day: outcome.day,
+                part: outcome.part.into(),
+                answer: None,
+                expected: expected.map(ToString::to_string),
+                status: Status::Fail,
+                parse_ns: None,
+                solve_ns: None,
+                error: None,
+            };
+            match &outcome.result {
+                Ok((parse, timed)) => {
+                    row.answer = Some(timed.answer.to_string());
+                    row.status = check(expected, &timed.answer);
+                    row.parse_ns = Some(parse.as_nanos() as u64);
+                    row.solve_ns = Some(timed.elapsed.as_nanos() as u64);
+                }
+                Err(failure) => {
+                    row.status = failure.status();
+                    row.error = Some(failure.to_string());
+                }
+            }
+            row
+        })
+        .collect()
+}
+
+pub fn write_json(rows: &[Row], mut out: impl Write) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut out, rows)?;
+    writeln!(out)
+}
+
+/// Quotes a field if it holds anything that would break the line up.
+fn csv_field(value: &Value) -> String {
+    let text = match value {
+        Value::Null => return String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+pub fn write_csv(rows: &[Row], mut out: impl Write) -> io::Result<()> {
+    writeln!(out, "{}", COLUMNS.join(","))?;
+    for row in rows {
+        let value = serde_json::to_value(row)?;
+        let fields: Vec<String> = COLUMNS
+            .iter()
+            .map(|column| csv_field(&value[column]))
+            .collect();
+        writeln!(out, "{}", fields.join(","))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Answer;
+
+    use super::*;
+
+    fn result() -> DayResult {
+        let timed = |answer, nanos| Timed {
+            answer,
+            elapsed: Duration::from_nanos(nanos),
+            alloc: None,
+        };
+        DayResult {
+            year: 2015,
+            day: 7,
+            parse: Duration::from_nanos(1500),
+            parse_alloc: None,
+            parts: vec![
+                (Part::One, timed(Answer::Unsigned(956), 2000)),
+                (
+                    Part::Two,
+                    timed(Answer::Text(String::from("a,\"b\"")), 3000),
+                ),
+            ],
+        }
+    }
+
+    fn solved() -> Vec<PartOutcome> {
+        split(2015, 7, &[Part::One, Part::Two], Ok(result()))
+    }
+
+    #[test]
+    fn test_rows() {
+        let answers = BTreeMap::new();
+        let rows = rows(&solved(), &answers);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].answer.as_deref(), Some("956"));
+        assert_eq!(rows[0].status, Status::Missing);
+        assert_eq!(
+            (rows[1].parse_ns, rows[1].solve_ns),
+            (Some(1500), Some(3000))
+        );
+
+        let failed = Err(Failure::Parse(String::from("expected a number")));
+        let rows = super::rows(&split(2015, 7, &[Part::Two], failed), &answers);
+        assert_eq!(rows[0].part, 2);
+        assert_eq!(rows[0].status, Status::Fail);
+        assert_eq!(rows[0].error.as_deref(), Some("expected a number"));
+
+        let timed_out = PartOutcome {
+            year: 2015,
+            day: 7,
+            part: Part::One,
+            result: Err(Failure::TimedOut(Duration::from_secs(10))),
+        };
+        let rows = super::rows(&[timed_out], &answers);
+        assert_eq!(rows[0].status, Status::TimedOut);
+        assert_eq!(rows[0].error.as_deref(), Some("timed out after 10.00s"));
+    }
+
+    #[test]
+    fn test_csv() {
+        let rows = rows(&solved(), &BTreeMap::new());
+        let mut out = Vec::new();
+        write_csv(&rows, &mut out).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "year,day,part,answer,expected,status,parse_ns,solve_ns,error\n\
+             2015,7,1,956,,missing,1500,2000,\n\
+             2015,7,2,\"a,\"\"b\"\"\",,missing,1500,3000,\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let failed = Err(Failure::NoInput(String::from("no input")));
+        let rows = rows(&split(2025, 1, &[Part::One], failed), &BTreeMap::new());
+        let mut out = Vec::new();
+        write_json(&rows, &mut out).unwrap();
+
+        let value: Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(value[0]["status"], "no_input");
+        assert_eq!(value[0]["answer"], Value::Null);
+        assert_eq!(value[0]["error"], "no input");
+    }
+}
@@ -0,0 +1,413 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::stars::days_in;
+
+const CARGO_TOML: &str = include_str!("../templates/day/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/day/lib.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/day/main.rs.tmpl");
+const ANSWERS_TOML: &str = include_str!("../templates/day/answers.toml.tmpl");
+
+/// The files a new day is registered in, relative to the workspace root.
+const WORKSPACE: &str = "Cargo.toml";
+const RUNNER: &str = "aoc/Cargo.toml";
+const REGISTRY: &str = "aoc/src/registry.rs";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The year's calendar stops before this day.
+    NoSuchDay {
+        year: u16,
+        day: u8,
+    },
+    /// Part of the day is already there, so nothing was written.
+    Exists(PathBuf),
+    /// A file the day is registered in isn't laid out as expected.
+    Unrecognised {
+        path: PathBuf,
+        missing: String,
+    },
+    Io {
+        path: PathBuf,
+        err: io::Error,
+    },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSuchDay { year, day } => {
+                write!(f, "{} only has {} days, not {}", year, days_in(*year), day)
+            }
+            Self::Exists(path) => write!(f, "{} already exists", path.display()),
+            Self::Unrecognised { path, missing } => {
+                write!(f, "{}: unable to find {}", path.display(), missing)
+            }
+            Self::Io { path, err } => write!(f, "{}: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+}
+
+/// Reads the year and day out of a line naming a day crate, such as
+/// `y2015-d1 = ...` or `&Erased::<y2015_d1::Day1>::new(),`.
+fn day_key(line: &str) -> Option<(u16, u8)> {
+    let rest = &line[line.find('y')? + 1..];
+    let year_end = rest.find(|c: char| !c.is_ascii_digit())?;
+    let year = u16::from_str_radix(&rest[..year_end], 10).ok()?;
+
+    let rest = rest[year_end..].get(1..)?.strip_prefix('d')?;
+    let day_end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let day = u8::from_str_radix(&rest[..day_end], 10).ok()?;
+
+    Some((year, day))
+}
+
+/// Adds `line` for a day between the `days:start` and `days:end` markers,
+/// keeping the days in order.
+fn register(
+    path: &Path,
+    text: &str,
+    year: u16,
+    day: u8,
+    line: &str,
+) -> Result<String, ScaffoldError> {
+    let lines: Vec<&str> = text.lines().collect();
+    let marker = |name: &str| {
+        lines
+            .iter()
+            .position(|line| line.trim_end().ends_with(name))
+            .ok_or_else(|| ScaffoldError::Unrecognised {
+                path: path.to_owned(),
+                missing: format!("the `{}` marker", name),
+            })
+    };
+    let (start, end) = (marker("days:start")?, marker("days:end")?);
+
+    let days = &lines[start + 1..end];
+    if days.iter().any(|line| day_key(line) == Some((year, day))) {
+        return Err(ScaffoldError::Exists(path.to_owned()));
+    }
+    let at = days
+        .iter()
+        .position(|line| day_key(line).is_some_and(|key| key > (year, day)))
+        .unwrap_or(days.len());
+    let indent = &lines[start][..lines[start].len() - lines[start].trim_start().len()];
+
+    let mut registered: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    registered.insert(start + 1 + at, format!("{}{}", indent, line));
+    Ok(registered.join("\n") + "\n")
+}
+
+/// Adds `y<year>/*` to the workspace members if the year is new.
+fn add_member(path: &Path, text: &str, year: u16) -> Result<String, ScaffoldError> {
+    let unrecognised = || ScaffoldError::Unrecognised {
+        path: path.to_owned(),
+        missing: String::from("a single line `members = [...]`"),
+    };
+
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+    let line = lines
+        .iter_mut()
+        .find(|line| line.starts_with("members = ["))
+        .ok_or_else(unrecognised)?;
+    let list = line
+        .strip_prefix("members = [")
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or_else(unrecognised)?;
+
+    let member = format!("\"y{}/*\"", year);
+    let mut members: Vec<&str> = list.split(',').map(str::trim).collect();
+    if !members.contains(&member.as_str()) {
+        members.push(&member);
+        members.sort();
+    }
+    *line = format!("members = [{}]", members.join(", "));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates the crate, the example answers and the registrations for a new
+/// day under the workspace at `root`, returning every path written.
+///
+/// Everything is checked before anything is written, so a day that's partly
+/// there is left alone.
+pub fn scaffold(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if day > days_in(year) {
+        return Err(ScaffoldError::NoSuchDay { year, day });
+    }
+
+    let crate_dir = root.join(format!("y{}/d{}", year, day));
+    let examples_dir = root.join(format!("examples/{}/{}", year, day));
+    for dir in [&crate_dir, &examples_dir] {
+        if dir.exists() {
+            return Err(ScaffoldError::Exists(dir.clone()));
+        }
+    }
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|err| ScaffoldError::Io {
+            path: path.to_owned(),
+            err,
+        })
+    };
+
+    let workspace = root.join(WORKSPACE);
+    let runner = root.join(RUNNER);
+    let registry = root.join(REGISTRY);
+    let mut files = Vec::new();
+    // NOTE: the workspace only changes for a new year
+    let members = read(&workspace)?;
+    let with_year = add_member(&workspace, &members, year)?;
+    if with_year != members {
+        files.push((workspace, with_year));
+    }
+    files.extend([
+        (
+            runner.clone(),
+            register(
+                &runner,
+                &read(&runner)?,
+                year,
+                day,
+                &format!("y{year}-d{day} = {{ path = \"../y{year}/d{day}\" }}"),
+            )?,
+        ),
+        (
+            registry.clone(),
+            register(
+                &registry,
+                &read(&registry)?,
+                year,
+                day,
+                &format!("&Erased::<y{year}_d{day}::Day{day}>::new(),"),
+            )?,
+        ),
+        (crate_dir.join("Cargo.toml"), render(CARGO_TOML, year, day)),
+        (crate_dir.join("src/lib.rs"), render(LIB_RS, year, day)),
+        (crate_dir.join("src/main.rs"), render(MAIN_RS, year, day)),
+        (examples_dir.join("answers.toml"), ANSWERS_TOML.to_owned()),
+    ]);
+
+    let mut written = Vec::new();
+    for (path, contents) in files {
+        let write = || -> io::Result<()> {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, contents)
+        };
+        write().map_err(|err| ScaffoldError::Io {
+            path: path.clone(),
+            err,
+        })?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    /// A workspace with two days in each of two years.
+    fn workspace(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+
+        fs::write(
+            root.join(WORKSPACE),
+            "[workspace]\nmembers = [\"aoc\", \"y2015/*\", \"y2025/*\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join(RUNNER),
+            "[dependencies]\n\
+             # days:start\n\
+             y2015-d1 = { path = \"../y2015/d1\" }\n\
+             y2015-d15 = { path = \"../y2015/d15\" }\n\
+             y2025-d1 = { path = \"../y2025/d1\" }\n\
+             y2025-d12 = { path = \"../y2025/d12\" }\n\
+             # days:end\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join(REGISTRY),
+            "pub static SOLUTIONS: &[&dyn DynSolution] = &[\n    \
+             // days:start\n    \
+             &Erased::<y2015_d1::Day1>::new(),\n    \
+             &Erased::<y2015_d15::Day15>::new(),\n    \
+             &Erased::<y2025_d1::Day1>::new(),\n    \
+             &Erased::<y2025_d12::Day12>::new(),\n    \
+             // days:end\n\
+             ];\n",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn test_day_key() {
+        assert_eq!(
+            day_key("y2015-d12 = { path = \"../y2015/d12\" }"),
+            Some((2015, 12))
+        );
+        assert_eq!(
+            day_key("    &Erased::<y2025_d3::Day3>::new(),"),
+            Some((2025, 3))
+        );
+        assert_eq!(day_key("    // days:start"), None);
+    }
+
+    #[test]
+    fn test_workspace_has_markers() {
+        let path = Path::new("");
+        register(path, include_str!("../Cargo.toml"), 9999, 1, "y9999-d1").unwrap();
+        register(path, include_str!("registry.rs"), 9999, 1, "y9999_d1").unwrap();
+        add_member(path, include_str!("../../Cargo.toml"), 9999).unwrap();
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = workspace("new");
+        let written = scaffold(&root, 2015, 2).unwrap();
+        assert_eq!(written.len(), 6);
+
+        let registry = fs::read_to_string(root.join(REGISTRY)).unwrap();
+        assert!(registry.contains(
+            "    &Erased::<y2015_d1::Day1>::new(),\n    \
+             &Erased::<y2015_d2::Day2>::new(),\n    \
+             &Erased::<y2015_d15::Day15>::new(),\n"
+        ));
+        let runner = fs::read_to_string(root.join(RUNNER)).unwrap();
+        assert!(runner.contains(
+            "y2015-d1 = { path = \"../y2015/d1\" }\n\
+             y2015-d2 = { path = \"../y2015/d2\" }\n\
+             y2015-d15 = { path = \"../y2015/d15\" }\n"
+        ));
+
+        let lib = fs::read_to_string(root.join("y2015/d2/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day2;"));
+        assert!(lib.contains("const YEAR: u16 = 2015;"));
+        assert!(!lib.contains("{day}") && !lib.contains("{year}"));
+        let main = fs::read_to_string(root.join("y2015/d2/src/main.rs")).unwrap();
+        assert!(main.contains("aoc_core::run::<y2015_d2::Day2>();"));
+        assert!(root.join("examples/2015/2/answers.toml").exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_scaffold_past_the_calendar() {
+        let root = workspace("past-the-calendar");
+        assert!(matches!(
+            scaffold(&root, 2025, 13),
+            Err(ScaffoldError::NoSuchDay {
+                year: 2025,
+                day: 13
+            })
+        ));
+        assert!(!root.join("y2025/d13").exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    /// Builds a freshly made day against the real `aoc-core`, since a template
+    /// that's drifted from the `Solution` trait still renders fine.
+    #[test]
+    fn test_scaffold_compiles() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let root = workspace("compiles");
+        scaffold(&root, 2015, 2).unwrap();
+
+        let path = |crate_name: &str| repo.join(crate_name).display().to_string();
+        fs::write(
+            root.join(WORKSPACE),
+            format!(
+                "[workspace]\n\
+                 resolver = \"3\"\n\
+                 members = [\"y2015/d2\"]\n\n\
+                 [workspace.package]\n\
+                 version = \"0.1.0\"\n\
+                 edition = \"2024\"\n\n\
+                 [workspace.dependencies]\n\
+                 aoc-core = {{ path = {:?} }}\n\
+                 aoc-macros = {{ path = {:?} }}\n\n\
+                 [workspace.lints.clippy]\n\
+                 from_str_radix_10 = \"allow\"\n",
+                path("aoc-core"),
+                path("aoc-macros")
+            ),
+        )
+        .unwrap();
+        // NOTE: the real lock file keeps this offline, cargo drops what isn't used
+        fs::copy(repo.join("Cargo.lock"), root.join("Cargo.lock")).unwrap();
+
+        let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+        let output = std::process::Command::new(cargo)
+            .args(["check", "--offline", "--quiet", "--all-targets"])
+            .current_dir(&root)
+            .env("CARGO_TARGET_DIR", repo.join("target/scaffold-check"))
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_new_year() {
+        let root = workspace("year");
+        scaffold(&root, 2016, 1).unwrap();
+
+        let workspace = fs::read_to_string(root.join(WORKSPACE)).unwrap();
+        assert!(workspace.contains("members = [\"aoc\", \"y2015/*\", \"y2016/*\", \"y2025/*\"]"));
+        let registry = fs::read_to_string(root.join(REGISTRY)).unwrap();
+        assert!(registry.contains(
+            "    &Erased::<y2015_d15::Day15>::new(),\n    &Erased::<y2016_d1::Day1>::new(),\n"
+        ));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_refuses_to_overwrite() {
+        let root = workspace("exists");
+        scaffold(&root, 2015, 2).unwrap();
+        let registry = fs::read_to_string(root.join(REGISTRY)).unwrap();
+
+        assert!(matches!(
+            scaffold(&root, 2015, 2),
+            Err(ScaffoldError::Exists(path)) if path == root.join("y2015/d2")
+        ));
+        // a day that's registered without a crate is still left alone
+        assert!(matches!(
+            scaffold(&root, 2015, 1),
+            Err(ScaffoldError::Exists(path)) if path == root.join(RUNNER)
+        ));
+        assert!(!root.join("y2015/d1").exists());
+        assert_eq!(fs::read_to_string(root.join(REGISTRY)).unwrap(), registry);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc_core::{DynSolution, Part};
+
+use crate::answers::Answers;
+
+pub const README_PATH: &str = "README.md";
+const START: &str = "<!-- stars:start -->";
+const END: &str = "<!-- stars:end -->";
+
+#[derive(Debug)]
+pub enum StarsError {
+    /// The README doesn't have both markers, in order.
+    NoMarkers(PathBuf),
+    Io {
+        path: PathBuf,
+        err: io::Error,
+    },
+}
+
+impl Display for StarsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoMarkers(path) => write!(
+                f,
+                "{}: unable to find `{}` followed by `{}`",
+                path.display(),
+                START,
+                END
+            ),
+            Self::Io { path, err } => write!(f, "{}: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for StarsError {}
+
+/// How many days a year's calendar has.
+pub(crate) fn days_in(year: u16) -> u8 {
+    // NOTE: the calendar was cut to 12 days from 2025 on
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// The weekday of the 1st of December, counting from Monday as 0.
+fn first_weekday(year: u16) -> usize {
+    // NOTE: Sakamoto's method, which counts from Sunday
+    let y = year as usize;
+    let sunday_based = (y + y / 4 - y / 100 + y / 400 + 4 + 1) % 7;
+    (sunday_based + 6) % 7
+}
+
+/// A Markdown calendar of one year, with each implemented day linked to its
+/// crate and showing a star for each part with an accepted answer.
+pub fn calendar(year: u16, implemented: &BTreeSet<u8>, answers: &Answers) -> String {
+    let mut cells = vec![String::new(); first_weekday(year)];
+    let mut stars = 0;
+    for day in 1..=days_in(year) {
+        if !implemented.contains(&day) {
+            cells.push(day.to_string());
+            continue;
+        }
+        let marks: String = [Part::One, Part::Two]
+            .into_iter()
+            .map(|part| match answers.get(day, part) {
+                Some(_) => {
+                    stars += 1;
+                    '★'
+                }
+                None => '☆',
+            })
+            .collect();
+        cells.push(format!("[{}](y{}/d{}) {}", day, year, day, marks));
+    }
+    while !cells.len().is_multiple_of(7) {
+        cells.push(String::new());
+    }
+
+    let mut markdown = format!(
+        "### {}\n\n{} of {} stars\n\n\
+         | Mon | Tue | Wed | Thu | Fri | Sat | Sun |\n\
+         |-----|-----|-----|-----|-----|-----|-----|\n",
+        year,
+        stars,
+        days_in(year) as usize * 2
+    );
+    for week in cells.chunks(7) {
+        markdown.push_str(&format!("| {} |\n", week.join(" | ")));
+    }
+    markdown
+}
+
+/// Replaces whatever is between the markers in `readme` with `calendars`.
+fn inject(readme: &str, calendars: &str, path: &Path) -> Result<String, StarsError> {
+    let no_markers = || StarsError::NoMarkers(path.to_owned());
+    let start = readme.find(START).ok_or_else(no_markers)? + START.len();
+    let end = start + readme[start..].find(END).ok_or_else(no_markers)?;
+
+    Ok(format!(
+        "{}\n{}{}",
+        &readme[..start],
+        calendars,
+        &readme[end..]
+    ))
+}
+
+/// Prints the calendars of every year with a day in `solutions`, or writes
+/// them into the README between the markers.
+pub fn stars(solutions: &[&dyn DynSolution], write: bool) -> ExitCode {
+    let mut years: BTreeMap<u16, BTreeSet<u8>> = BTreeMap::new();
+    for solution in solutions {
+        years
+            .entry(solution.year())
+            .or_default()
+            .insert(solution.day());
+    }
+    let answers = match Answers::load_years(years.keys().copied()) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let calendars: Vec<String> = years
+        .iter()
+        .map(|(year, days)| calendar(*year, days, &answers[year]))
+        .collect();
+    let calendars = calendars.join("\n");
+    if !write {
+        print!("{}", calendars);
+        return ExitCode::SUCCESS;
+    }
+
+    let path = Path::new(README_PATH);
+    let io_err = |err| StarsError::Io {
+        path: path.to_owned(),
+        err,
+    };
+    let written = fs::read_to_string(path)
+        .map_err(io_err)
+        .and_then(|readme| inject(&readme, &calendars, path))
+        .and_then(|readme| fs::write(path, readme).map_err(io_err));
+    match written {
+        Ok(()) => {
+            println!("updated {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_weekday() {
+        // 2015-12-01 was a Tuesday, 2025-12-01 a Monday, 2024-12-01 a Sunday
+        assert_eq!(first_weekday(2015), 1);
+        assert_eq!(first_weekday(2025), 0);
+        assert_eq!(first_weekday(2024), 6);
+    }
+
+    #[test]
+    fn test_calendar() {
+        let answers =
+            Answers::parse("[1]\npart1 = 1\npart2 = 2\n[2]\npart2 = 3\n", Path::new("")).unwrap();
+        let implemented = BTreeSet::from([1, 2, 3]);
+        let markdown = calendar(2025, &implemented, &answers);
+
+        assert_eq!(
+            markdown,
+            "### 2025\n\n3 of 24 stars\n\n\
+             | Mon | Tue | Wed | Thu | Fri | Sat | Sun |\n\
+             |-----|-----|-----|-----|-----|-----|-----|\n\
+             | [1](y2025/d1) ★★ | [2](y2025/d2) ☆★ | [3](y2025/d3) ☆☆ | 4 | 5 | 6 | 7 |\n\
+             | 8 | 9 | 10 | 11 | 12 |  |  |\n"
+        );
+    }
+
+    #[test]
+    fn test_inject() {
+        let path = Path::new("README.md");
+        let readme = format!("# AoC\n\n{}\nold\n{}\n\nmore\n", START, END);
+        assert_eq!(
+            inject(&readme, "new\n", path).unwrap(),
+            format!("# AoC\n\n{}\nnew\n{}\n\nmore\n", START, END)
+        );
+        assert!(matches!(
+            inject("# AoC\n", "new\n", path),
+            Err(StarsError::NoMarkers(_))
+        ));
+    }
+}
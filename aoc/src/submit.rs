@@ -0,0 +1,514 @@
+use std::{
+    fmt::Display,
+    path::Path,
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_core::{Answer, DynSolution, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{Client, ClientError},
+    config::Config,
+    history::{self, HistoryError},
+    html,
+};
+
+/// Where every submitted answer is appended, one JSON object per line.
+pub const ATTEMPTS_PATH: &str = ".aoc/attempts.jsonl";
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the last attempt, so the answer wasn't checked.
+    RateLimited {
+        wait_secs: u64,
+    },
+    /// The part is already solved, or it's part 2 and part 1 isn't.
+    WrongLevel,
+    /// A reply we don't recognise, kept as its text.
+    Unknown(String),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::TooHigh => write!(f, "wrong, too high"),
+            Self::TooLow => write!(f, "wrong, too low"),
+            Self::RateLimited { wait_secs } => {
+                write!(f, "rate limited, wait {}s before trying again", wait_secs)
+            }
+            Self::WrongLevel => write!(f, "not the right level, is it already solved?"),
+            Self::Unknown(text) => write!(f, "unrecognised reply: {}", text),
+        }
+    }
+}
+
+/// Reads a wait like `1m 5s` out of "You have 1m 5s left to wait".
+fn parse_wait(text: &str) -> Option<u64> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    text[start..end]
+        .split_whitespace()
+        .try_fold(0, |total, part| {
+            let (count, unit) = part.split_at(part.len().checked_sub(1)?);
+            let count = u64::from_str_radix(count, 10).ok()?;
+            let scale = match unit {
+                "h" => 60 * 60,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+            Some(total + count * scale)
+        })
+}
+
+impl Verdict {
+    /// Makes sense of the page the site replies to a submission with.
+    pub fn parse(page: &str) -> Self {
+        let text = match html::elements(page, "article").first() {
+            Some(article) => html::text(article),
+            None => html::text(page),
+        };
+
+        if text.starts_with("That's the right answer") {
+            Self::Correct
+        } else if text.starts_with("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Self::TooHigh
+            } else if text.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            }
+        } else if text.starts_with("You gave an answer too recently") {
+            // NOTE: a minute is what the site asks for after a first wrong answer
+            Self::RateLimited {
+                wait_secs: parse_wait(&text).unwrap_or(60),
+            }
+        } else if text.starts_with("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unknown(text)
+        }
+    }
+}
+
+/// One answer sent to the site, and what it said.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Why an answer wasn't sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    Unsolved,
+    AlreadySolved {
+        answer: String,
+    },
+    AlreadyRejected {
+        verdict: Verdict,
+    },
+    /// At or above an answer the site said was too high.
+    AboveTooHigh {
+        bound: String,
+    },
+    /// At or below an answer the site said was too low.
+    BelowTooLow {
+        bound: String,
+    },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsolved => write!(f, "there's no answer to submit"),
+            Self::AlreadySolved { answer } => write!(f, "already solved with {}", answer),
+            Self::AlreadyRejected { verdict } => write!(f, "already submitted, it was {}", verdict),
+            Self::AboveTooHigh { bound } => write!(f, "{} was already too high", bound),
+            Self::BelowTooLow { bound } => write!(f, "{} was already too low", bound),
+        }
+    }
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+/// Checks an answer against earlier attempts at the same part, refusing
+/// anything the site has already ruled out.
+pub fn guard(
+    attempts: &[Attempt],
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<(), Refusal> {
+    if !answer.is_solved() {
+        return Err(Refusal::Unsolved);
+    }
+    let answer = answer.to_string();
+    // NOTE: i128 holds every answer whether it came from an i64 or a u64
+    let value = |answer: &str| answer.parse::<i128>().ok();
+
+    let earlier: Vec<&Attempt> = attempts
+        .iter()
+        .filter(|a| a.year == year && a.day == day && a.part == part_number(part))
+        .collect();
+
+    for attempt in &earlier {
+        match attempt.verdict {
+            Verdict::Correct => {
+                return Err(Refusal::AlreadySolved {
+                    answer: attempt.answer.clone(),
+                });
+            }
+            Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow if attempt.answer == answer => {
+                return Err(Refusal::AlreadyRejected {
+                    verdict: attempt.verdict.clone(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    let Some(current) = value(&answer) else {
+        return Ok(());
+    };
+    let bound = |verdict: Verdict| {
+        earlier
+            .iter()
+            .filter(move |a| a.verdict == verdict)
+            .filter_map(|a| Some((value(&a.answer)?, &a.answer)))
+    };
+
+    if let Some((_, bound)) = bound(Verdict::TooHigh)
+        .filter(|&(high, _)| current >= high)
+        .min()
+    {
+        return Err(Refusal::AboveTooHigh {
+            bound: bound.clone(),
+        });
+    }
+    if let Some((_, bound)) = bound(Verdict::TooLow)
+        .filter(|&(low, _)| current <= low)
+        .max()
+    {
+        return Err(Refusal::BelowTooLow {
+            bound: bound.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Client(ClientError),
+    History(HistoryError),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Refused(refusal) => write!(f, "not submitting, {}", refusal),
+            Self::Client(err) => write!(f, "{}", err),
+            Self::History(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<ClientError> for SubmitError {
+    fn from(err: ClientError) -> Self {
+        Self::Client(err)
+    }
+}
+
+impl From<HistoryError> for SubmitError {
+    fn from(err: HistoryError) -> Self {
+        Self::History(err)
+    }
+}
+
+/// Guards, submits and records an answer.
+pub fn submit_answer(
+    client: &Client,
+    attempts_path: &Path,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Verdict, SubmitError> {
+    let attempts: Vec<Attempt> = history::load(attempts_path)?;
+    guard(&attempts, year, day, part, answer).map_err(SubmitError::Refused)?;
+
+    let answer = answer.to_string();
+    let page = client.submit(year, day, part, &answer)?;
+    let verdict = Verdict::parse(&page);
+
+    let attempt = Attempt {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        year,
+        day,
+        part: part_number(part),
+        answer,
+        verdict: verdict.clone(),
+    };
+    history::append(attempts_path, &[attempt])?;
+
+    Ok(verdict)
+}
+
+pub fn submit(solution: &dyn DynSolution, part: Part, input: Option<&str>) -> ExitCode {
+    let (year, day) = (solution.year(), solution.day());
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = match aoc_core::load_input(year, day, input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match solution.solve(&input, Some(part)) {
+        Ok(result) => result,
+        Err(err) => {
+            eprintln!("{} day {}: {}", year, day, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let answer = &result.parts[0].1.answer;
+    println!("{} day {} part {}: {}", year, day, part, answer);
+
+    match submit_answer(
+        &Client::from_config(&config),
+        Path::new(ATTEMPTS_PATH),
+        year,
+        day,
+        part,
+        answer,
+    ) {
+        Ok(verdict) => {
+            println!("{}", verdict);
+            if verdict == Verdict::Correct {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf};
+
+    use super::*;
+    use crate::mock::MockServer;
+
+    fn attempt(answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            timestamp: 0,
+            year: 2015,
+            day: 9,
+            part: 1,
+            answer: answer.to_owned(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let pages = [
+            (
+                include_str!("../fixtures/submit/correct.html"),
+                Verdict::Correct,
+            ),
+            (
+                include_str!("../fixtures/submit/wrong.html"),
+                Verdict::Wrong,
+            ),
+            (
+                include_str!("../fixtures/submit/too-high.html"),
+                Verdict::TooHigh,
+            ),
+            (
+                include_str!("../fixtures/submit/too-low.html"),
+                Verdict::TooLow,
+            ),
+            (
+                include_str!("../fixtures/submit/rate-limited.html"),
+                Verdict::RateLimited { wait_secs: 65 },
+            ),
+            (
+                include_str!("../fixtures/submit/wrong-level.html"),
+                Verdict::WrongLevel,
+            ),
+        ];
+        for (page, verdict) in pages {
+            assert_eq!(Verdict::parse(page), verdict);
+        }
+
+        assert_eq!(
+            Verdict::parse("<article><p>Something   else</p></article>"),
+            Verdict::Unknown(String::from("Something else"))
+        );
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(parse_wait("You have 34s left to wait."), Some(34));
+        assert_eq!(parse_wait("You have 5m left to wait."), Some(300));
+        assert_eq!(parse_wait("You have 1m 5s left to wait."), Some(65));
+        assert_eq!(parse_wait("You have a while left to wait."), None);
+    }
+
+    #[test]
+    fn test_guard() {
+        let attempts = [
+            attempt("500", Verdict::TooHigh),
+            attempt("800", Verdict::TooHigh),
+            attempt("100", Verdict::TooLow),
+            attempt("250", Verdict::Wrong),
+            attempt("abc", Verdict::Wrong),
+            attempt("300", Verdict::RateLimited { wait_secs: 30 }),
+        ];
+        let check = |answer: Answer| guard(&attempts, 2015, 9, Part::One, &answer);
+
+        assert_eq!(check(Answer::from(300u64)), Ok(()));
+        assert_eq!(
+            check(Answer::from(-5i64)),
+            Err(Refusal::BelowTooLow {
+                bound: String::from("100")
+            })
+        );
+        assert_eq!(
+            check(Answer::from(250u64)),
+            Err(Refusal::AlreadyRejected {
+                verdict: Verdict::Wrong
+            })
+        );
+        assert_eq!(
+            check(Answer::from(600u64)),
+            Err(Refusal::AboveTooHigh {
+                bound: String::from("500")
+            })
+        );
+        assert_eq!(
+            check(Answer::from(100i64)),
+            Err(Refusal::AlreadyRejected {
+                verdict: Verdict::TooLow
+            })
+        );
+        assert_eq!(
+            check(Answer::from(99u64)),
+            Err(Refusal::BelowTooLow {
+                bound: String::from("100")
+            })
+        );
+        assert_eq!(
+            check(Answer::from("abc")),
+            Err(Refusal::AlreadyRejected {
+                verdict: Verdict::Wrong
+            })
+        );
+        assert_eq!(check(Answer::Unsolved), Err(Refusal::Unsolved));
+
+        // other parts and days aren't bounded by these
+        assert_eq!(
+            guard(&attempts, 2015, 9, Part::Two, &Answer::from(600u64)),
+            Ok(())
+        );
+        assert_eq!(
+            guard(&attempts, 2015, 10, Part::One, &Answer::from(600u64)),
+            Ok(())
+        );
+
+        let solved = [attempt("42", Verdict::Correct)];
+        assert_eq!(
+            guard(&solved, 2015, 9, Part::One, &Answer::from(42u64)),
+            Err(Refusal::AlreadySolved {
+                answer: String::from("42")
+            })
+        );
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let server = MockServer::start(|request| match request.body.as_str() {
+            "level=1&answer=900" => (
+                200,
+                include_str!("../fixtures/submit/too-high.html").to_owned(),
+            ),
+            "level=1&answer=141" => (
+                200,
+                include_str!("../fixtures/submit/correct.html").to_owned(),
+            ),
+            _ => (200, String::new()),
+        });
+        let client = Client::new(&server.url, Some(String::from("0123abcd")));
+        let path: PathBuf =
+            env::temp_dir().join(format!("aoc-submit-{}/attempts.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let submit =
+            |answer: u64| submit_answer(&client, &path, 2015, 9, Part::One, &Answer::from(answer));
+
+        assert_eq!(submit(900).unwrap(), Verdict::TooHigh);
+        assert!(matches!(
+            submit(1000),
+            Err(SubmitError::Refused(Refusal::AboveTooHigh { .. }))
+        ));
+        assert_eq!(submit(141).unwrap(), Verdict::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2015/day/9/answer");
+        assert_eq!(requests[0].header("cookie"), Some("session=0123abcd"));
+
+        let attempts: Vec<Attempt> = history::load(&path).unwrap();
+        assert_eq!(
+            attempts
+                .iter()
+                .map(|a| (a.answer.as_str(), a.verdict.clone()))
+                .collect::<Vec<_>>(),
+            [("900", Verdict::TooHigh), ("141", Verdict::Correct)]
+        );
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,142 @@
+use std::{collections::BTreeMap, fmt::Display, process::ExitCode};
+
+use aoc_core::{Answer, DynSolution, Part};
+use serde::Serialize;
+
+use crate::{answers::Answers, table::print_table};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Pass,
+    Fail,
+    /// There is no accepted answer recorded to check against.
+    Missing,
+    NoInput,
+    /// Only reported when each part runs in its own process.
+    TimedOut,
+    Panicked,
+    OutOfMemory,
+    /// The process died some other way, such as being killed.
+    Crashed,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail => write!(f, "FAIL"),
+            Self::Missing => write!(f, "missing"),
+            Self::NoInput => write!(f, "no input"),
+            Self::TimedOut => write!(f, "timed out"),
+            Self::Panicked => write!(f, "panicked"),
+            Self::OutOfMemory => write!(f, "out of memory"),
+            Self::Crashed => write!(f, "crashed"),
+        }
+    }
+}
+
+pub fn check(expected: Option<&Answer>, actual: &Answer) -> Status {
+    match expected {
+        None => Status::Missing,
+        Some(expected) if expected == actual => Status::Pass,
+        Some(_) => Status::Fail,
+    }
+}
+
+/// Runs each solution and compares it against the accepted answers.
+pub fn verify(solutions: &[&dyn DynSolution]) -> ExitCode {
+    let answers = match Answers::load_years(solutions.iter().map(|s| s.year())) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut rows = Vec::new();
+    let mut counts: BTreeMap<Status, usize> = BTreeMap::new();
+
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let answers = &answers[&year];
+
+        // NOTE: without an answer, the error stands in for the status of both parts
+        let results: Vec<(Part, Result<Answer, Status>)> =
+            match aoc_core::load_input(year, day, None) {
+                Ok(input) => match solution.solve(&input, None) {
+                    Ok(result) => result
+                        .parts
+                        .into_iter()
+                        .map(|(part, timed)| (part, Ok(timed.answer)))
+                        .collect(),
+                    Err(err) => {
+                        eprintln!("{} day {}: {}", year, day, err);
+                        vec![
+                            (Part::One, Err(Status::Fail)),
+                            (Part::Two, Err(Status::Fail)),
+                        ]
+                    }
+                },
+                Err(_) => vec![
+                    (Part::One, Err(Status::NoInput)),
+                    (Part::Two, Err(Status::NoInput)),
+                ],
+            };
+
+        for (part, actual) in results {
+            let expected = answers.get(day, part);
+            let status = match &actual {
+                Ok(actual) => check(expected, actual),
+                Err(status) => *status,
+            };
+            *counts.entry(status).or_default() += 1;
+
+            let or_dash =
+                |answer: Option<&Answer>| answer.map_or(String::from("-"), Answer::to_string);
+            rows.push([
+                year.to_string(),
+                day.to_string(),
+                part.to_string(),
+                or_dash(expected),
+                or_dash(actual.as_ref().ok()),
+                status.to_string(),
+            ]);
+        }
+    }
+
+    print_table(
+        ["year", "day", "part", "expected", "actual", "status"],
+        &rows,
+    );
+
+    let count = |status| counts.get(&status).copied().unwrap_or(0);
+    println!(
+        "{} passed, {} failed, {} missing, {} without input",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing),
+        count(Status::NoInput)
+    );
+
+    if count(Status::Fail) > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let answer = Answer::Unsigned(42);
+
+        assert_eq!(check(Some(&Answer::Signed(42)), &answer), Status::Pass);
+        assert_eq!(check(Some(&Answer::Unsigned(41)), &answer), Status::Fail);
+        assert_eq!(check(Some(&answer), &Answer::Unsolved), Status::Fail);
+        assert_eq!(check(None, &answer), Status::Missing);
+    }
+}
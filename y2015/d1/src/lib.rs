@@ -0,0 +1,74 @@
+use aoc_core::{AocError, Assumption, Input, Rng, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+
+    type Parsed = String;
+    type Part1 = i64;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        Ok(input.trim().to_owned())
+    }
+
+    fn part1(input: &Self::Parsed) -> Self::Part1 {
+        let ups = input.chars().filter(|c| *c == '(').count();
+        let downs = input.chars().filter(|c| *c == ')').count();
+
+        ups as i64 - downs as i64
+    }
+
+    fn part2(input: &Self::Parsed) -> Self::Part2 {
+        let mut acc = 0i64;
+        for (i, c) in input.char_indices() {
+            acc = match c {
+                '(' => acc + 1,
+                ')' => acc - 1,
+                _ => acc,
+            };
+            if acc < 0 {
+                return i + 1;
+            };
+        }
+        panic!("santa never enters the basement")
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Parsed>> {
+        vec![Assumption::new("Santa reaches the basement", |input| {
+            input
+                .chars()
+                .scan(0i64, |floor, c| {
+                    *floor += match c {
+                        '(' => 1,
+                        ')' => -1,
+                        _ => 0,
+                    };
+                    Some(*floor)
+                })
+                .any(|floor| floor < 0)
+        })]
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let mut moves = String::with_capacity(7000 * scale);
+        let mut floor = 0i64;
+        let mut basement = false;
+        for _ in 0..7000 * scale {
+            // NOTE: lean upwards so the basement isn't reached straight away
+            let up = rng.chance(0.55);
+            floor += if up { 1 } else { -1 };
+            basement |= floor < 0;
+            moves.push(if up { '(' } else { ')' });
+        }
+        if !basement {
+            moves.push_str(&")".repeat(floor as usize + 1));
+        }
+        Some(moves)
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day1);
@@ -0,0 +1,97 @@
+use aoc_core::{AocError, Input, Rng, Solution};
+
+fn do_round(input: String) -> String {
+    let mut out = String::with_capacity(input.len());
+
+    let mut char = None;
+    let mut count = 0;
+    for c in input.chars() {
+        if let Some(m) = char {
+            if c == m {
+                count += 1;
+            } else {
+                out.push_str(&format!("{}", count));
+                out.push(m);
+                char = Some(c);
+                count = 1;
+            }
+        } else {
+            char = Some(c);
+            count = 1;
+        }
+    }
+
+    out.push_str(&format!("{}", count));
+    out.push(char.unwrap());
+
+    out
+}
+
+fn look_and_say(input: &str, rounds: usize) -> usize {
+    let mut input = input.to_owned();
+
+    for _ in 0..rounds {
+        input = do_round(input)
+    }
+
+    input.len()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 10;
+
+    type Parsed = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        let sequence = input.trim();
+        if sequence.is_empty() {
+            return Err(AocError::after(sequence, "a sequence to start from"));
+        }
+        Ok(sequence.to_owned())
+    }
+
+    fn part1(input: &Self::Parsed) -> Self::Part1 {
+        look_and_say(input, 40)
+    }
+
+    fn part2(input: &Self::Parsed) -> Self::Part2 {
+        look_and_say(input, 50)
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(
+            (0..10 * scale)
+                .map(|_| *rng.pick(&['1', '2', '3']))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        assert_eq!(do_round(String::from("1")), "11")
+    }
+
+    #[test]
+    fn test_example_rounds() {
+        let mut sequence = include_str!("../../../examples/2015/10/1.txt")
+            .trim()
+            .to_owned();
+        for expected in ["11", "21", "1211", "111221", "312211"] {
+            sequence = do_round(sequence);
+            assert_eq!(sequence, expected);
+        }
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day10);
@@ -0,0 +1,142 @@
+use aoc_core::{AocError, Input, Rng, Solution};
+use fancy_regex::Regex;
+
+fn check_pass(pass: &str) -> bool {
+    if pass.contains(['i', 'o', 'l']) {
+        false
+    } else {
+        let doublet_re = Regex::new("(.)\\1.*(.)\\2").unwrap();
+        if !doublet_re.is_match(pass).unwrap() {
+            false
+        } else {
+            let mut prev_char: Option<char> = None;
+            let mut seq_len = 1;
+            for c in pass.chars() {
+                match prev_char {
+                    None => prev_char = Some(c),
+                    Some(prev) => {
+                        if (c as u8).checked_sub(prev as u8) == Some(1) {
+                            prev_char = Some(c);
+                            seq_len += 1
+                        } else {
+                            prev_char = Some(c);
+                            seq_len = 1;
+                        }
+                    }
+                }
+                if seq_len == 3 {
+                    return true;
+                }
+            }
+            false
+        }
+    }
+}
+
+/// The next letter, and whether it wrapped round to `a` and carries.
+fn increment_char(c: char) -> (char, bool) {
+    match c {
+        'a'..='y' => ((c as u8 + 1) as char, false),
+        'z' => ('a', true),
+        // NOTE: parse only lets letters through, anything else starts over
+        _ => ('a', false),
+    }
+}
+
+fn increment_str(input: &str) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+    for c in chars.iter_mut().rev() {
+        let carry;
+        (*c, carry) = increment_char(*c);
+        if !carry {
+            break;
+        }
+    }
+    chars.into_iter().collect()
+}
+
+fn next_valid(mut pass: String) -> String {
+    while !check_pass(&pass) {
+        pass = increment_str(&pass);
+    }
+
+    pass
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 11;
+
+    type Parsed = String;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        let pass = input.trim();
+        if let Some((i, c)) = pass.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            return Err(AocError::new(
+                &pass[i..i + c.len_utf8()],
+                "a lowercase letter",
+            ));
+        }
+        // NOTE: shorter passwords can never be valid, so the search would never end
+        if pass.len() != 8 {
+            return Err(AocError::new(pass, "a password of eight letters"));
+        }
+        Ok(pass.to_owned())
+    }
+
+    fn part1(input: &Self::Parsed) -> Self::Part1 {
+        next_valid(input.clone())
+    }
+
+    fn part2(input: &Self::Parsed) -> Self::Part2 {
+        let pass = Self::part1(input);
+        next_valid(increment_str(&pass))
+    }
+
+    fn generate(rng: &mut Rng, _scale: usize) -> Option<String> {
+        // NOTE: passwords are always eight letters, so there's nothing to scale, and
+        // the forbidden letters are left out as they'd make the search crawl
+        const LETTERS: &[u8] = b"abcdefghjkmnpqrstuvwxy";
+        Some((0..8).map(|_| *rng.pick(LETTERS) as char).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test0() {
+        assert!(!check_pass("hijklmmn"))
+    }
+
+    #[test]
+    fn test1() {
+        assert!(!check_pass("abbceffg"))
+    }
+
+    #[test]
+    fn test_increment() {
+        assert_eq!(increment_str("xx"), "xy");
+        assert_eq!(increment_str("xz"), "ya");
+        assert_eq!(increment_str("zz"), "aa");
+    }
+
+    #[test]
+    fn test_parse_rejects_other_letters() {
+        let text = "abcDefgh\n";
+        let err = Day11::parse(&Input::new(text)).unwrap_err().locate(text);
+        assert_eq!(err.text, "D");
+        assert_eq!(err.location.unwrap().column, 4);
+
+        assert!(Day11::parse(&Input::new("abcdéfgh")).is_err());
+        assert!(Day11::parse(&Input::new("abc")).is_err());
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day11);
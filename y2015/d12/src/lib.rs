@@ -0,0 +1,95 @@
+use aoc_core::{AocError, Input, Rng, Solution};
+use serde_json::Value;
+
+fn tree_sum(value: &Value, ignore_red: bool) -> i64 {
+    match value {
+        Value::Null => 0,
+        Value::Bool(_) => 0,
+        Value::Number(i) => i.as_i64().unwrap(),
+        Value::String(_) => 0,
+        Value::Array(arr) => arr.iter().map(|v| tree_sum(v, ignore_red)).sum(),
+        Value::Object(map) => {
+            if !ignore_red
+                || (!map.contains_key("red") && map.values().filter(|v| *v == "red").count() == 0)
+            {
+                map.values().map(|v| tree_sum(v, ignore_red)).sum()
+            } else {
+                0
+            }
+        }
+    }
+}
+
+/// A random JSON value nested at most `depth` deep.
+fn random_value(rng: &mut Rng, depth: usize) -> Value {
+    let kinds = if depth == 0 || rng.chance(0.4) { 2 } else { 4 };
+    match rng.below(kinds) {
+        0 => Value::from(rng.range(-50..=200)),
+        1 => Value::from(*rng.pick(&["red", "green", "blue", "orange", "violet", "yellow"])),
+        2 => Value::Array(
+            (0..rng.range(1..=6))
+                .map(|_| random_value(rng, depth - 1))
+                .collect(),
+        ),
+        _ => {
+            let mut keys: Vec<char> = ('a'..='j').collect();
+            rng.shuffle(&mut keys);
+            let len = rng.range(1..=6) as usize;
+            Value::Object(
+                keys[..len]
+                    .iter()
+                    .map(|key| (key.to_string(), random_value(rng, depth - 1)))
+                    .collect(),
+            )
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 12;
+
+    type Parsed = Value;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        let json = input.trim();
+        serde_json::from_str(json).map_err(|err| {
+            // NOTE: serde counts columns in bytes, so find the character it means
+            let line_start: usize = json
+                .split_inclusive('\n')
+                .take(err.line().saturating_sub(1))
+                .map(str::len)
+                .sum();
+            let mut at = (line_start + err.column().saturating_sub(1)).min(json.len());
+            while !json.is_char_boundary(at) {
+                at -= 1;
+            }
+            let found = json[at..]
+                .chars()
+                .next()
+                .map_or(&json[at..], |c| &json[at..at + c.len_utf8()]);
+
+            AocError::new(found, "valid JSON")
+        })
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Self::Part1 {
+        tree_sum(parsed, false)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Self::Part2 {
+        tree_sum(parsed, true)
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let document = Value::Array((0..50 * scale).map(|_| random_value(rng, 4)).collect());
+        Some(document.to_string())
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day12);
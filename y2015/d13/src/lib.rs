@@ -0,0 +1,313 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use aoc_core::{AocError, Assumption, Input, Rng, Solution};
+use aoc_macros::AocParse;
+use tracing::trace;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, AocParse)]
+pub enum RelationType {
+    #[aoc(pattern = "gain")]
+    Good,
+    #[aoc(pattern = "lose")]
+    Bad,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Relation {
+    t: RelationType,
+    amount: u32,
+}
+
+impl From<&Relation> for i64 {
+    fn from(relation: &Relation) -> Self {
+        match relation.t {
+            RelationType::Good => relation.amount as i64,
+            RelationType::Bad => -(relation.amount as i64),
+        }
+    }
+}
+
+#[derive(Debug, Eq)]
+struct TwoPeople<'a> {
+    a: &'a str,
+    b: &'a str,
+}
+
+impl<'a> PartialEq for TwoPeople<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a && self.b == other.b || self.a == other.b && self.b == other.a
+    }
+}
+
+impl<'a> Hash for TwoPeople<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        if self.a < self.b {
+            self.a.hash(state);
+            self.b.hash(state);
+        } else {
+            self.b.hash(state);
+            self.a.hash(state);
+        }
+    }
+}
+
+fn solve(
+    people: &Vec<&str>,
+    selected: &Vec<bool>,
+    relations: &HashMap<TwoPeople, i64>,
+    prev_person: Option<&str>,
+    first_person: Option<&str>,
+    depth: usize,
+    seated: &Vec<&str>,
+) -> i64 {
+    trace!(depth, ?first_person, ?prev_person, "seating");
+    assert_eq!(people.len(), selected.len());
+    let res = match (first_person, prev_person) {
+        (None, _) => (0..1)
+            .map(|i| {
+                trace!(depth, person = people[i], "first seat");
+                let mut new_selected = selected.to_owned();
+                new_selected[i] = true;
+                let person = Some(people[i]);
+
+                let mut new_seated = seated.clone();
+                new_seated.push(people[i]);
+
+                solve(
+                    people,
+                    &new_selected,
+                    relations,
+                    prev_person,
+                    person,
+                    depth + 1,
+                    &new_seated,
+                )
+            })
+            .max()
+            .unwrap(),
+        (Some(first), Some(prev)) => (0..people.len())
+            .filter(|i| !selected[*i])
+            .map(|i| {
+                trace!(depth, person = people[i], "next seat");
+                let mut new_selected = selected.to_owned();
+                new_selected[i] = true;
+                let person = Some(people[i]);
+
+                let r = relations
+                    .get(&TwoPeople {
+                        a: prev,
+                        b: people[i],
+                    })
+                    .unwrap();
+
+                let mut new_seated = seated.clone();
+                new_seated.push(people[i]);
+
+                solve(
+                    people,
+                    &new_selected,
+                    relations,
+                    person,
+                    first_person,
+                    depth + 1,
+                    &new_seated,
+                ) + r
+            })
+            .max()
+            .unwrap_or(*relations.get(&TwoPeople { a: prev, b: first }).unwrap()),
+        (Some(first), None) => (0..people.len())
+            .filter(|i| !selected[*i])
+            .map(|i| {
+                trace!(depth, person = people[i], "second seat");
+                let mut new_selected = selected.to_owned();
+                new_selected[i] = true;
+                let person = Some(people[i]);
+
+                let r = relations
+                    .get(&TwoPeople {
+                        a: first,
+                        b: people[i],
+                    })
+                    .unwrap();
+
+                let mut new_seated = seated.clone();
+                new_seated.push(people[i]);
+
+                solve(
+                    people,
+                    &new_selected,
+                    relations,
+                    person,
+                    first_person,
+                    depth + 1,
+                    &new_seated,
+                ) + r
+            })
+            .max()
+            .expect("oh no"),
+    };
+    trace!(depth, ?seated, happiness = res, "best seating");
+    res
+}
+
+fn optimal_happiness(records: &[(String, String, Relation)], include_me: bool) -> i64 {
+    let mut relations: HashMap<TwoPeople, i64> = HashMap::with_capacity(records.len() / 2);
+
+    for (a, b, relation) in records {
+        let two_people = TwoPeople { a, b };
+        if let Some(r) = relations.get_mut(&two_people) {
+            let v: i64 = relation.into();
+            *r += v
+        } else {
+            relations.insert(two_people, relation.into());
+        }
+    }
+
+    let people_a: HashSet<&str> = relations.iter().map(|relation| relation.0.a).collect();
+    let people_b: HashSet<&str> = relations.iter().map(|relation| relation.0.b).collect();
+    let mut people_vec: Vec<&str> = people_a.union(&people_b).copied().collect();
+
+    if include_me {
+        for person in people_vec.iter() {
+            relations.insert(TwoPeople { a: "Me", b: person }, 0);
+        }
+
+        people_vec.push("Me");
+    }
+
+    solve(
+        &people_vec,
+        &vec![false; people_vec.len()],
+        &relations,
+        None,
+        None,
+        0,
+        &vec![],
+    )
+}
+
+/// Everyone named in `records`.
+fn guests(records: &[(String, String, Relation)]) -> HashSet<&str> {
+    records
+        .iter()
+        .flat_map(|(a, b, _)| [a.as_str(), b.as_str()])
+        .collect()
+}
+
+#[derive(Debug, AocParse)]
+#[aoc(pattern = "{person} would {t} {amount} happiness units by sitting next to {neighbour}.")]
+struct Seating {
+    person: String,
+    t: RelationType,
+    amount: u32,
+    neighbour: String,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 13;
+
+    type Parsed = Vec<(String, String, Relation)>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        input
+            .lines()
+            .map(|line| {
+                let seating: Seating = line.parse()?;
+                Ok((
+                    seating.person,
+                    seating.neighbour,
+                    Relation {
+                        t: seating.t,
+                        amount: seating.amount,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    fn part1(records: &Self::Parsed) -> Self::Part1 {
+        optimal_happiness(records, false)
+    }
+
+    fn part2(records: &Self::Parsed) -> Self::Part2 {
+        optimal_happiness(records, true)
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Parsed>> {
+        vec![
+            Assumption::new("there are at least two guests", |records| {
+                guests(records).len() >= 2
+            }),
+            Assumption::new("every pair of guests has a happiness", |records| {
+                let guests = guests(records);
+                let pairs: HashSet<TwoPeople> =
+                    records.iter().map(|(a, b, _)| TwoPeople { a, b }).collect();
+                guests.iter().all(|a| {
+                    guests
+                        .iter()
+                        .all(|b| a == b || pairs.contains(&TwoPeople { a, b }))
+                })
+            }),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        // NOTE: seatings are tried in every order, so each step of scale adds a person
+        let people = rng.names(7 + scale);
+        let mut lines = Vec::new();
+        for a in &people {
+            for b in people.iter().filter(|b| *b != a) {
+                let change = rng.pick(&["gain", "lose"]);
+                lines.push(format!(
+                    "{} would {} {} happiness units by sitting next to {}.\n",
+                    a,
+                    change,
+                    rng.range(1..=100),
+                    b
+                ));
+            }
+        }
+        Some(lines.concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_seating() {
+        let seating: Seating = "Alice would lose 79 happiness units by sitting next to Carol."
+            .parse()
+            .unwrap();
+        assert_eq!(seating.person, "Alice");
+        assert_eq!(seating.t, RelationType::Bad);
+        assert_eq!(seating.amount, 79);
+        assert_eq!(seating.neighbour, "Carol");
+
+        let input = "Alice would win 79 happiness units by sitting next to Carol.";
+        let err = input.parse::<Seating>().unwrap_err().locate(input);
+        assert_eq!(err.text, "win");
+        assert_eq!(err.expected, "`gain` or `lose`");
+        assert_eq!(err.location.unwrap().column, 13);
+
+        let input = "Alice would gain 79 happiness points by sitting next to Carol.";
+        let err = input.parse::<Seating>().unwrap_err().locate(input);
+        assert_eq!(err.text, "points");
+        assert_eq!(err.location.unwrap().column, 31);
+
+        let input = "Alice would gain 79 happiness units by sitting next to Carol.!";
+        assert_eq!(input.parse::<Seating>().unwrap_err().text, "!");
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day13);
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use aoc_core::{AocError, Assumption, Input, Rng, Solution};
+use aoc_macros::AocParse;
+use tracing::debug;
+
+#[derive(Debug, PartialEq, AocParse)]
+#[aoc(
+    pattern = "{name} can fly {speed} km/s for {time} seconds, but then must rest for {rest} seconds."
+)]
+pub struct Reindeer {
+    name: String,
+    speed: u64,
+    time: u64,
+    rest: u64,
+}
+
+const RACE_LENGTH: u64 = 2503;
+
+fn distance(r: &Reindeer, race_length: u64) -> u64 {
+    let full = race_length / (r.time + r.rest);
+    let part = race_length % (r.time + r.rest);
+
+    (r.speed * (r.time * full)) + (part.min(r.time) * r.speed)
+}
+
+fn farthest(reindeer: &[Reindeer], race_length: u64) -> u64 {
+    reindeer
+        .iter()
+        .map(|r| distance(r, race_length))
+        .max()
+        .unwrap()
+}
+
+fn most_points(reindeer: &[Reindeer], race_length: u64) -> u64 {
+    let mut score_card: HashMap<&str, u64> = HashMap::new();
+
+    // NOTE: points are handed out at the end of each second, so second 0 doesn't count,
+    // and every reindeer tied for the lead gets one
+    for i in 1..=race_length {
+        let lead = farthest(reindeer, i);
+        for r in reindeer.iter().filter(|r| distance(r, i) == lead) {
+            *score_card.entry(&r.name).or_default() += 1;
+        }
+    }
+
+    debug!(?score_card);
+
+    score_card.into_values().max().unwrap()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 14;
+
+    type Parsed = Vec<Reindeer>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        let reindeer: Vec<Reindeer> = input.lines().map(str::parse).collect::<Result<_, _>>()?;
+
+        debug!(?reindeer);
+
+        Ok(reindeer)
+    }
+
+    fn part1(reindeer: &Self::Parsed) -> Self::Part1 {
+        farthest(reindeer, RACE_LENGTH)
+    }
+
+    fn part2(reindeer: &Self::Parsed) -> Self::Part2 {
+        most_points(reindeer, RACE_LENGTH)
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Parsed>> {
+        vec![
+            Assumption::new("there is at least one reindeer", |reindeer| {
+                !reindeer.is_empty()
+            }),
+            Assumption::new("every reindeer flies or rests for some time", |reindeer| {
+                reindeer
+                    .iter()
+                    .all(|r| r.time.checked_add(r.rest).is_some_and(|cycle| cycle > 0))
+            }),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let lines: Vec<String> = rng
+            .names(9 * scale)
+            .into_iter()
+            .map(|name| {
+                format!(
+                    "{} can fly {} km/s for {} seconds, but then must rest for {} seconds.\n",
+                    name,
+                    rng.range(2..=30),
+                    rng.range(2..=20),
+                    rng.range(20..=180)
+                )
+            })
+            .collect();
+        Some(lines.concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_race() {
+        let input = Input::new(include_str!("../../../examples/2015/14/1.txt"));
+        let reindeer = Day14::parse(&input).unwrap();
+
+        assert_eq!(farthest(&reindeer, 1000), 1120);
+        assert_eq!(most_points(&reindeer, 1000), 689);
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day14);
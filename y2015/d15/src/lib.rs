@@ -0,0 +1,120 @@
+use aoc_core::{AocError, Input, Rng, Solution};
+use aoc_macros::AocParse;
+use tracing::debug;
+
+#[derive(Debug, PartialEq, AocParse)]
+#[aoc(
+    pattern = "{name}: capacity {capacity}, durability {durability}, flavor {flavour}, texture {texture}, calories {calories}"
+)]
+pub struct Ingredient {
+    name: String,
+    capacity: i64,
+    durability: i64,
+    flavour: i64,
+    texture: i64,
+    calories: i64,
+}
+
+const TEASPOONS: i64 = 100;
+const CALORIES: i64 = 500;
+
+/// The totals of each property in a recipe, in the order capacity, durability, flavour,
+/// texture, calories.
+type Totals = [i64; 5];
+
+fn score(totals: &Totals) -> u64 {
+    totals[..4].iter().map(|&t| t.max(0) as u64).product()
+}
+
+/// The best score of any recipe using the remaining teaspoons on `ingredients`, on top of
+/// `totals`, optionally only counting recipes with exactly `calories`.
+fn best(ingredients: &[Ingredient], teaspoons: i64, totals: Totals, calories: Option<i64>) -> u64 {
+    let Some((ingredient, rest)) = ingredients.split_first() else {
+        return 0;
+    };
+    // NOTE: the last ingredient takes whatever is left, so every recipe uses all the teaspoons
+    let amounts = if rest.is_empty() {
+        teaspoons..=teaspoons
+    } else {
+        0..=teaspoons
+    };
+    amounts
+        .map(|amount| {
+            let properties = [
+                ingredient.capacity,
+                ingredient.durability,
+                ingredient.flavour,
+                ingredient.texture,
+                ingredient.calories,
+            ];
+            let mut totals = totals;
+            for (total, property) in totals.iter_mut().zip(properties) {
+                *total += amount * property;
+            }
+            if !rest.is_empty() {
+                best(rest, teaspoons - amount, totals, calories)
+            } else if calories.is_none_or(|calories| calories == totals[4]) {
+                score(&totals)
+            } else {
+                0
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 15;
+
+    type Parsed = Vec<Ingredient>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        let ingredients: Vec<Ingredient> =
+            input.lines().map(str::parse).collect::<Result<_, _>>()?;
+
+        debug!(?ingredients);
+
+        Ok(ingredients)
+    }
+
+    fn part1(ingredients: &Self::Parsed) -> Self::Part1 {
+        best(ingredients, TEASPOONS, [0; 5], None)
+    }
+
+    fn part2(ingredients: &Self::Parsed) -> Self::Part2 {
+        best(ingredients, TEASPOONS, [0; 5], Some(CALORIES))
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        // NOTE: every extra ingredient multiplies the recipes to search by about a hundred,
+        // so there are always four like the real input, and the scale widens the properties.
+        // Past a scale of 100 the product of four totals could overflow the score
+        let spread = 5 * scale.min(100) as i64;
+        let lines: Vec<String> = rng
+            .names(4)
+            .into_iter()
+            .map(|name| {
+                let [capacity, durability, flavour, texture] =
+                    [(); 4].map(|_| rng.range(-spread..=spread));
+                format!(
+                    "{}: capacity {}, durability {}, flavor {}, texture {}, calories {}\n",
+                    name,
+                    capacity,
+                    durability,
+                    flavour,
+                    texture,
+                    rng.range(1..=8)
+                )
+            })
+            .collect();
+        Some(lines.concat())
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day15);
@@ -0,0 +1,72 @@
+use aoc_core::{AocError, Input, Rng, Solution, error::number};
+
+fn part_1(cuboids: &[Vec<u32>]) -> u32 {
+    cuboids
+        .iter()
+        .map(|cuboid| {
+            assert_eq!(cuboid.len(), 3);
+            cuboid.first().unwrap() * cuboid.get(1).unwrap() * 3
+                + cuboid.first().unwrap() * cuboid.get(2).unwrap() * 2
+                + cuboid.get(1).unwrap() * cuboid.get(2).unwrap() * 2
+        })
+        .sum()
+}
+
+fn part_2(cuboids: &[Vec<u32>]) -> u32 {
+    cuboids
+        .iter()
+        .map(|cuboid| {
+            assert_eq!(cuboid.len(), 3);
+            cuboid.first().unwrap() * 2
+                + cuboid.get(1).unwrap() * 2
+                + cuboid.first().unwrap() * cuboid.get(1).unwrap() * cuboid.get(2).unwrap()
+        })
+        .sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Vec<u32>>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        input
+            .lines()
+            .map(|line| {
+                let mut dimentions: Vec<u32> =
+                    line.split('x').map(number).collect::<Result<_, _>>()?;
+                if dimentions.len() != 3 {
+                    return Err(AocError::new(line, "three dimensions like 2x3x4"));
+                }
+                dimentions.sort();
+                Ok(dimentions)
+            })
+            .collect()
+    }
+
+    fn part1(cuboids: &Self::Parsed) -> Self::Part1 {
+        part_1(cuboids)
+    }
+
+    fn part2(cuboids: &Self::Parsed) -> Self::Part2 {
+        part_2(cuboids)
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let presents: Vec<String> = (0..1000 * scale)
+            .map(|_| {
+                let [l, w, h] = [(); 3].map(|_| rng.range(1..=30));
+                format!("{}x{}x{}\n", l, w, h)
+            })
+            .collect();
+        Some(presents.concat())
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day2);
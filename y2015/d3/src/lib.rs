@@ -0,0 +1,93 @@
+use std::{collections::HashSet, str::Chars};
+
+use aoc_core::{AocError, Input, Rng, Solution};
+
+fn generate_location_set(moves: Vec<char>) -> HashSet<(i32, i32)> {
+    let mut locations: HashSet<(i32, i32)> = HashSet::new();
+
+    let mut current = (0, 0);
+    locations.insert(current);
+    for m in moves {
+        match m {
+            '^' => current.1 += 1,
+            'v' => current.1 -= 1,
+            '>' => current.0 += 1,
+            '<' => current.0 -= 1,
+            _ => {}
+        }
+
+        locations.insert(current);
+    }
+
+    locations
+}
+
+fn part_1(moves: Chars) -> usize {
+    generate_location_set(moves.collect()).len()
+}
+
+fn alternate_moves(moves: Chars) -> (Vec<char>, Vec<char>) {
+    (
+        moves.to_owned().step_by(2).collect(),
+        moves.skip(1).step_by(2).collect(),
+    )
+}
+
+fn part_2(moves: Chars) -> usize {
+    let (a, b) = alternate_moves(moves);
+
+    let santa = generate_location_set(a);
+    let robo = generate_location_set(b);
+
+    santa.union(&robo).count()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+
+    type Parsed = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        Ok(input.trim().to_owned())
+    }
+
+    fn part1(moves: &Self::Parsed) -> Self::Part1 {
+        part_1(moves.chars())
+    }
+
+    fn part2(moves: &Self::Parsed) -> Self::Part2 {
+        part_2(moves.chars())
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(
+            (0..8192 * scale)
+                .map(|_| *rng.pick(&['^', 'v', '<', '>']))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alternate() {
+        assert_eq!(
+            alternate_moves("<><><><><><>".chars()),
+            (
+                "<<<<<<".chars().collect::<Vec<char>>(),
+                ">>>>>>".chars().collect::<Vec<char>>()
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day3);
@@ -0,0 +1,58 @@
+use aoc_core::{AocError, Input, Rng, Solution};
+use md5::compute;
+
+fn has_leading_zeros(hash: &[u8], zeros: usize) -> bool {
+    // each byte is two hex digits, so an odd count also checks the top half of the next byte
+    hash.iter().take(zeros / 2).all(|b| *b == 0)
+        && (zeros.is_multiple_of(2) || hash[zeros / 2] & 0xf0 == 0)
+}
+
+fn find_hash_conflict(input: &str, zeros: usize) -> usize {
+    let mut i = 1;
+    loop {
+        let hash = compute(format!("{}{}", input, i));
+
+        if has_leading_zeros(&hash.0, zeros) {
+            return i;
+        }
+
+        i += 1;
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 4;
+
+    type Parsed = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        let key = input.trim();
+        // NOTE: md5 of a bare number never starts with enough zeros, so the search would
+        // never end
+        if key.is_empty() {
+            return Err(AocError::after(key, "a secret key"));
+        }
+        Ok(key.to_owned())
+    }
+
+    fn part1(key: &Self::Parsed) -> Self::Part1 {
+        find_hash_conflict(key, 5)
+    }
+
+    fn part2(key: &Self::Parsed) -> Self::Part2 {
+        find_hash_conflict(key, 6)
+    }
+
+    fn generate(rng: &mut Rng, _scale: usize) -> Option<String> {
+        // NOTE: keys are always eight letters, so there's nothing to scale
+        Some(rng.word(8))
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day4);
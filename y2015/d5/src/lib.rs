@@ -0,0 +1,66 @@
+use aoc_core::{AocError, Input, Rng, Solution};
+use fancy_regex::Regex;
+
+fn check_nice(string: &str) -> bool {
+    let vowel_count = string
+        .chars()
+        .filter(|&c| c == 'a' || c == 'e' || c == 'i' || c == 'o' || c == 'u')
+        .count();
+
+    let disallowed = string.contains("ab")
+        || string.contains("cd")
+        || string.contains("pq")
+        || string.contains("xy");
+
+    let mut prev = ' ';
+    let mut has_duplicate = false;
+    for char in string.chars() {
+        if prev == char {
+            has_duplicate = true;
+            break;
+        }
+        prev = char
+    }
+
+    vowel_count >= 3 && !disallowed && has_duplicate
+}
+
+fn check_extra_nice(string: &str) -> bool {
+    let prop1 = Regex::new("(..).*\\1").unwrap();
+    let prop2 = Regex::new("(.).\\1").unwrap();
+
+    prop1.is_match(string).unwrap() && prop2.is_match(string).unwrap()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 5;
+
+    type Parsed = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        Ok(input.lines().map(|line| line.to_owned()).collect())
+    }
+
+    fn part1(lines: &Self::Parsed) -> Self::Part1 {
+        lines.iter().filter(|line| check_nice(line)).count()
+    }
+
+    fn part2(lines: &Self::Parsed) -> Self::Part2 {
+        lines.iter().filter(|line| check_extra_nice(line)).count()
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let lines: Vec<String> = (0..1000 * scale)
+            .map(|_| format!("{}\n", rng.word(16)))
+            .collect();
+        Some(lines.concat())
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day5);
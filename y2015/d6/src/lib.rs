@@ -0,0 +1,110 @@
+use aoc_core::{Answer, AocError, Input, Point, Rng, Solution, error::split_once};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    action: Action,
+    a: Point<usize>,
+    b: Point<usize>,
+}
+
+fn parse_range(string: &str) -> Result<(Point<usize>, Point<usize>), AocError> {
+    let (a, b) = split_once(string.trim(), " through ")?;
+    let corner = |text: &str| -> Result<Point<usize>, AocError> {
+        let point: Point<usize> = text.parse()?;
+        if point.x >= 1000 || point.y >= 1000 {
+            return Err(AocError::new(text, "a light in the 1000x1000 grid"));
+        }
+        Ok(point)
+    };
+
+    Ok((corner(a)?, corner(b)?))
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = AocError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (action, rem) = if let Some(rem) = line.strip_prefix("turn on ") {
+            (Action::TurnOn, rem)
+        } else if let Some(rem) = line.strip_prefix("turn off ") {
+            (Action::TurnOff, rem)
+        } else if let Some(rem) = line.strip_prefix("toggle ") {
+            (Action::Toggle, rem)
+        } else {
+            let word = line.split(' ').next().unwrap_or(line);
+            return Err(AocError::new(word, "`turn on`, `turn off` or `toggle`"));
+        };
+        let (a, b) = parse_range(rem)?;
+
+        Ok(Self { action, a, b })
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 6;
+
+    type Parsed = Vec<Instruction>;
+    type Part1 = Answer;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        input.lines().map(Instruction::try_from).collect()
+    }
+
+    fn part1(_instructions: &Self::Parsed) -> Self::Part1 {
+        Answer::Unsolved
+    }
+
+    fn part2(instructions: &Self::Parsed) -> Self::Part2 {
+        let mut space = vec![[0u32; 1000]; 1000];
+
+        for Instruction { action, a, b } in instructions {
+            for x in a.x.min(b.x)..=a.x.max(b.x) {
+                for y in a.y.min(b.y)..=a.y.max(b.y) {
+                    let light = space.get_mut(x).unwrap().get_mut(y).unwrap();
+                    *light = match action {
+                        Action::TurnOn => light.checked_add(1).expect("overflowed"),
+                        Action::TurnOff => light.saturating_sub_signed(1),
+                        Action::Toggle => light.checked_add(2).expect("overflowed"),
+                    };
+                }
+            }
+        }
+
+        space
+            .into_iter()
+            .map(|row| row.into_iter().map(|v| v as usize).sum::<usize>())
+            .sum::<usize>()
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let lines: Vec<String> = (0..300 * scale)
+            .map(|_| {
+                let action = rng.pick(&["turn on", "turn off", "toggle"]);
+                let [x1, x2, y1, y2] = [(); 4].map(|_| rng.range(0..=999));
+                format!(
+                    "{} {},{} through {},{}\n",
+                    action,
+                    x1.min(x2),
+                    y1.min(y2),
+                    x1.max(x2),
+                    y1.max(y2)
+                )
+            })
+            .collect();
+        Some(lines.concat())
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day6);
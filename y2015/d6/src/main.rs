@@ -1,90 +1,3 @@
-use std::fs;
-
-#[derive(Debug, Clone, Copy)]
-struct Point {
-    x: usize,
-    y: usize,
-}
-
-impl From<&str> for Point {
-    fn from(value: &str) -> Self {
-        let parts: Vec<&str> = value.split(',').collect();
-
-        Point {
-            x: usize::from_str_radix(parts.get(0).unwrap(), 10).unwrap(),
-            y: usize::from_str_radix(parts.get(1).unwrap(), 10).unwrap(),
-        }
-    }
-}
-
-fn parse_range(string: &str) -> (Point, Point) {
-    let parts: Vec<&str> = string.trim().split(" through ").collect();
-
-    (
-        Point::from(*parts.get(0).unwrap()),
-        Point::from(*parts.get(1).unwrap()),
-    )
-}
-
 fn main() {
-    let input = fs::read_to_string("input.txt").unwrap();
-
-    let mut space = [[0u8; 1000]; 1000];
-
-    let lines: Vec<&str> = input.split('\n').filter(|line| line.len() > 0).collect();
-
-    for line in lines {
-        if let Some(rem) = line.strip_prefix("turn on ") {
-            let (a, b) = parse_range(rem);
-
-            for x in a.x.min(b.x)..=a.x.max(b.x) {
-                for y in a.y.min(b.y)..=a.y.max(b.y) {
-                    *space.get_mut(x).unwrap().get_mut(y).unwrap() = space
-                        .get(x)
-                        .unwrap()
-                        .get(y)
-                        .unwrap()
-                        .checked_add(1)
-                        .expect("overflowed");
-                }
-            }
-        } else if let Some(rem) = line.strip_prefix("turn off ") {
-            let (a, b) = parse_range(rem);
-
-            for x in a.x.min(b.x)..=a.x.max(b.x) {
-                for y in a.y.min(b.y)..=a.y.max(b.y) {
-                    *space.get_mut(x).unwrap().get_mut(y).unwrap() = space
-                        .get(x)
-                        .unwrap()
-                        .get(y)
-                        .unwrap()
-                        .saturating_sub_signed(1);
-                }
-            }
-        } else if let Some(rem) = line.strip_prefix("toggle ") {
-            let (a, b) = parse_range(rem);
-
-            for x in a.x.min(b.x)..=a.x.max(b.x) {
-                for y in a.y.min(b.y)..=a.y.max(b.y) {
-                    *space.get_mut(x).unwrap().get_mut(y).unwrap() = space
-                        .get(x)
-                        .unwrap()
-                        .get(y)
-                        .unwrap()
-                        .checked_add(2)
-                        .expect("overflowed");
-                }
-            }
-        } else {
-            unreachable!()
-        }
-    }
-
-    println!(
-        "{}",
-        space
-            .into_iter()
-            .map(|row| row.into_iter().map(|v| v as usize).sum::<usize>())
-            .sum::<usize>()
-    );
+    aoc_core::run::<y2015_d6::Day6>();
 }
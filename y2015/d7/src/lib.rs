@@ -0,0 +1,583 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::Display,
+};
+
+use aoc_core::{AocError, Assumption, Input, Rng, Solution};
+use nom::{
+    IResult, Parser,
+    branch::alt,
+    bytes::complete::{tag, take_till},
+    combinator::map_res,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Operator {
+    AND,
+    OR,
+    NOT,
+    LSHIFT,
+    RSHIFT,
+    ASSIGN,
+}
+
+#[derive(Debug)]
+enum ParseOperatorError {
+    UnknownOperator(String),
+}
+
+impl Display for ParseOperatorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownOperator(value) => writeln!(f, "{} is an unknown operator", value),
+        }
+    }
+}
+
+impl Error for ParseOperatorError {}
+
+impl Operator {
+    fn from_str(value: &str) -> Result<Self, ParseOperatorError> {
+        match value {
+            "AND" => Ok(Operator::AND),
+            "OR" => Ok(Operator::OR),
+            "NOT" => Ok(Operator::NOT),
+            "LSHIFT" => Ok(Operator::LSHIFT),
+            "RSHIFT" => Ok(Operator::RSHIFT),
+            _ => Err(ParseOperatorError::UnknownOperator(value.to_owned())),
+        }
+    }
+}
+
+fn operator(input: &str) -> IResult<&str, Operator> {
+    map_res(take_till(|c| c == ' ' || c == '\n'), Operator::from_str).parse(input)
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Variable {
+    name: String,
+}
+
+impl Variable {
+    fn from_str(input: &str) -> Self {
+        Self {
+            name: input.to_owned(),
+        }
+    }
+}
+
+fn variable(input: &str) -> IResult<&str, Variable> {
+    take_till(|c| c == ' ' || c == '\n')
+        .map(Variable::from_str)
+        .parse(input)
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    VAR(Variable),
+    LIT(u16),
+}
+
+enum OperandParseError {}
+
+impl Operand {
+    fn from_str(input: &str) -> Result<Operand, OperandParseError> {
+        if let Ok(i) = u16::from_str_radix(input, 10) {
+            Ok(Operand::LIT(i))
+        } else {
+            Ok(Operand::VAR(Variable::from_str(input)))
+        }
+    }
+}
+
+fn operand(input: &str) -> IResult<&str, Operand> {
+    map_res(take_till(|c| c == ' ' || c == '\n'), Operand::from_str).parse(input)
+}
+
+#[derive(Debug, Clone)]
+pub struct Expr {
+    operator: Operator,
+    operands: Vec<Operand>,
+    destination: Variable,
+}
+
+fn assign_expr(input: &str) -> IResult<&str, Expr> {
+    let (input, a) = operand(input)?;
+    let (input, _) = tag(" -> ")(input)?;
+    let (input, dest) = variable(input)?;
+
+    Ok((
+        input,
+        Expr {
+            operator: Operator::ASSIGN,
+            operands: vec![a],
+            destination: dest,
+        },
+    ))
+}
+
+fn single_operand_expr(input: &str) -> IResult<&str, Expr> {
+    let (input, op) = operator(input)?;
+    let (input, _) = tag(" ")(input)?;
+    let (input, a) = operand(input)?;
+    let (input, _) = tag(" -> ")(input)?;
+    let (input, dest) = variable(input)?;
+
+    Ok((
+        input,
+        Expr {
+            operator: op,
+            operands: vec![a],
+            destination: dest,
+        },
+    ))
+}
+
+fn double_operand_expr(input: &str) -> IResult<&str, Expr> {
+    let (input, a) = operand(input)?;
+    let (input, _) = tag(" ")(input)?;
+    let (input, op) = operator(input)?;
+    let (input, _) = tag(" ")(input)?;
+    let (input, b) = operand(input)?;
+    let (input, _) = tag(" -> ")(input)?;
+    let (input, dest) = variable(input)?;
+
+    Ok((
+        input,
+        Expr {
+            operator: op,
+            operands: vec![a, b],
+            destination: dest,
+        },
+    ))
+}
+
+fn expr(input: &str) -> IResult<&str, Expr> {
+    alt((single_operand_expr, double_operand_expr, assign_expr)).parse(input)
+}
+
+#[derive(Debug, Default)]
+struct Machine<'a> {
+    state: HashMap<&'a Variable, u16>,
+}
+
+impl<'a> Machine<'a> {
+    #[inline]
+    fn apply(&mut self, expr: &'a Expr) -> bool {
+        match expr.operator {
+            Operator::ASSIGN => match expr.operands.first().unwrap() {
+                Operand::LIT(value) => {
+                    self.state.insert(&expr.destination, *value);
+                    true
+                }
+                Operand::VAR(name) => {
+                    if let Some(value) = self.state.get(name) {
+                        self.state.insert(&expr.destination, *value);
+                        true
+                    } else {
+                        false
+                    }
+                }
+            },
+            Operator::AND => match (
+                expr.operands.first().unwrap(),
+                expr.operands.get(1).unwrap(),
+            ) {
+                (Operand::LIT(a), Operand::LIT(b)) => {
+                    self.state.insert(&expr.destination, a & b);
+                    true
+                }
+                (Operand::LIT(a), Operand::VAR(b)) => {
+                    if let Some(value) = self.state.get(b) {
+                        self.state.insert(&expr.destination, a & value);
+                        true
+                    } else {
+                        false
+                    }
+                }
+                (Operand::VAR(a), Operand::LIT(b)) => {
+                    if let Some(value) = self.state.get(a) {
+                        self.state.insert(&expr.destination, value & b);
+                        true
+                    } else {
+                        false
+                    }
+                }
+                (Operand::VAR(a), Operand::VAR(b)) => {
+                    if let (Some(a_value), Some(b_value)) = (self.state.get(a), self.state.get(b)) {
+                        self.state.insert(&expr.destination, a_value & b_value);
+                        true
+                    } else {
+                        false
+                    }
+                }
+            },
+            Operator::OR => match (
+                expr.operands.first().unwrap(),
+                expr.operands.get(1).unwrap(),
+            ) {
+                (Operand::LIT(a), Operand::LIT(b)) => {
+                    self.state.insert(&expr.destination, a | b);
+                    true
+                }
+                (Operand::LIT(a), Operand::VAR(b)) => {
+                    if let Some(value) = self.state.get(b) {
+                        self.state.insert(&expr.destination, a | value);
+                        true
+                    } else {
+                        false
+                    }
+                }
+                (Operand::VAR(a), Operand::LIT(b)) => {
+                    if let Some(value) = self.state.get(a) {
+                        self.state.insert(&expr.destination, value | b);
+                        true
+                    } else {
+                        false
+                    }
+                }
+                (Operand::VAR(a), Operand::VAR(b)) => {
+                    if let (Some(a_value), Some(b_value)) = (self.state.get(a), self.state.get(b)) {
+                        self.state.insert(&expr.destination, a_value | b_value);
+                        true
+                    } else {
+                        false
+                    }
+                }
+            },
+            Operator::LSHIFT => {
+                match (
+                    expr.operands.first().unwrap(),
+                    expr.operands.get(1).unwrap(),
+                ) {
+                    (Operand::LIT(a), Operand::LIT(b)) => {
+                        self.state.insert(&expr.destination, a << b);
+                        true
+                    }
+                    (Operand::LIT(a), Operand::VAR(b)) => {
+                        if let Some(value) = self.state.get(b) {
+                            self.state.insert(&expr.destination, a << value);
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                    (Operand::VAR(a), Operand::LIT(b)) => {
+                        if let Some(value) = self.state.get(a) {
+                            self.state.insert(&expr.destination, value << b);
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                    (Operand::VAR(a), Operand::VAR(b)) => {
+                        if let (Some(a_value), Some(b_value)) =
+                            (self.state.get(a), self.state.get(b))
+                        {
+                            self.state.insert(&expr.destination, a_value << b_value);
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                }
+            }
+            Operator::RSHIFT => {
+                match (
+                    expr.operands.first().unwrap(),
+                    expr.operands.get(1).unwrap(),
+                ) {
+                    (Operand::LIT(a), Operand::LIT(b)) => {
+                        self.state.insert(&expr.destination, a >> b);
+                        true
+                    }
+                    (Operand::LIT(a), Operand::VAR(b)) => {
+                        if let Some(value) = self.state.get(b) {
+                            self.state.insert(&expr.destination, a >> value);
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                    (Operand::VAR(a), Operand::LIT(b)) => {
+                        if let Some(value) = self.state.get(a) {
+                            self.state.insert(&expr.destination, value >> b);
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                    (Operand::VAR(a), Operand::VAR(b)) => {
+                        if let (Some(a_value), Some(b_value)) =
+                            (self.state.get(a), self.state.get(b))
+                        {
+                            self.state.insert(&expr.destination, a_value >> b_value);
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                }
+            }
+            Operator::NOT => match expr.operands.first().unwrap() {
+                Operand::LIT(value) => {
+                    self.state.insert(&expr.destination, !*value);
+                    true
+                }
+                Operand::VAR(name) => {
+                    if let Some(value) = self.state.get(name) {
+                        self.state.insert(&expr.destination, !*value);
+                        true
+                    } else {
+                        false
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// The signal left on `wire` once every instruction has been applied.
+fn run(exprs: &[Expr], wire: &str) -> Result<u16, AocError> {
+    let mut machine = Machine::default();
+    let mut applied: HashSet<usize> = HashSet::new();
+
+    let exprs_len = exprs.len();
+    while applied.len() < exprs_len {
+        let before = applied.len();
+        for (i, expr) in exprs.iter().enumerate() {
+            if applied.contains(&i) {
+                continue;
+            }
+
+            if machine.apply(expr) {
+                applied.insert(i);
+            }
+        }
+        // NOTE: a pass that applies nothing means the rest wait on each other or on
+        // wires that are never driven, so another pass won't help
+        if applied.len() == before {
+            return Err(AocError::expected("a circuit where every wire settles"));
+        }
+    }
+
+    machine
+        .state
+        .get(&Variable::from_str(wire))
+        .copied()
+        .ok_or_else(|| AocError::expected(format!("a wire `{}`", wire)))
+}
+
+/// The wires each instruction reads.
+fn reads(expr: &Expr) -> impl Iterator<Item = &Variable> {
+    expr.operands.iter().filter_map(|operand| match operand {
+        Operand::VAR(name) => Some(name),
+        Operand::LIT(_) => None,
+    })
+}
+
+/// Whether every wire gets a signal, working out which can be driven from
+/// ones already known without computing any values.
+fn settles(exprs: &[Expr]) -> bool {
+    let mut known: HashSet<&Variable> = HashSet::new();
+    loop {
+        let before = known.len();
+        for expr in exprs {
+            if reads(expr).all(|name| known.contains(name)) {
+                known.insert(&expr.destination);
+            }
+        }
+        if known.len() == before {
+            return exprs.iter().all(|expr| known.contains(&expr.destination));
+        }
+    }
+}
+
+/// A random circuit of `count` wires, each gate reading only wires before it.
+fn circuit(rng: &mut Rng, count: usize) -> String {
+    let len = Rng::letters_for(count);
+    // NOTE: `b` comes first so it's driven by a signal, which part 2 replaces, and
+    // `a` last, and every gate only reads wires before it so the circuit settles
+    let mut wires = vec![String::from("b")];
+    wires.extend(rng.words(count - 2, len, &["a", "b"]));
+    wires.push(String::from("a"));
+
+    let inputs = count / 10;
+    // NOTE: the last wire `b` reached, so some gates read it and `a` always does,
+    // otherwise overriding `b` would rarely change `a`
+    let mut from_b = 0;
+    let mut lines: Vec<String> = Vec::with_capacity(count);
+    for (i, wire) in wires.iter().enumerate() {
+        if i < inputs.max(1) {
+            lines.push(format!("{} -> {}\n", rng.below(65536), wire));
+            continue;
+        }
+        let x = if i == count - 1 || rng.chance(0.3) {
+            let x = &wires[from_b];
+            from_b = i;
+            x
+        } else {
+            &wires[rng.index(i)]
+        };
+        let y = &wires[rng.index(i)];
+        let gate = match rng.below(6) {
+            0 => x.clone(),
+            1 => format!("NOT {}", x),
+            2 if rng.chance(0.2) => format!("1 AND {}", y),
+            2 => format!("{} AND {}", x, y),
+            3 => format!("{} OR {}", x, y),
+            4 => format!("{} LSHIFT {}", x, rng.range(1..=15)),
+            _ => format!("{} RSHIFT {}", x, rng.range(1..=15)),
+        };
+        lines.push(format!("{} -> {}\n", gate, wire));
+    }
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 7;
+
+    type Parsed = Vec<Expr>;
+    type Part1 = u16;
+    type Part2 = u16;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        const EXPECTED: &str = "an instruction like `x AND y -> z`";
+
+        input
+            .lines()
+            .map(|line| match expr(line) {
+                Ok(("", e)) => Ok(e),
+                Ok((rest, _)) => Err(AocError::new(rest, "the end of the line")),
+                Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+                    // NOTE: point at the word the parser got stuck on
+                    let rest = err.input.trim_start();
+                    let word = &rest[..rest.find(' ').unwrap_or(rest.len())];
+                    Err(AocError::new(word, EXPECTED))
+                }
+                Err(nom::Err::Incomplete(_)) => Err(AocError::after(line, EXPECTED)),
+            })
+            .collect()
+    }
+
+    fn part1(exprs: &Self::Parsed) -> Self::Part1 {
+        run(exprs, "a").expect("the assumptions hold")
+    }
+
+    fn part2(exprs: &Self::Parsed) -> Self::Part2 {
+        let a = Self::part1(exprs);
+
+        let mut exprs: Vec<Expr> = exprs
+            .iter()
+            .filter(|expr| expr.destination != Variable::from_str("b"))
+            .cloned()
+            .collect();
+
+        exprs.push(Expr {
+            operator: Operator::ASSIGN,
+            operands: vec![Operand::LIT(a)],
+            destination: Variable::from_str("b"),
+        });
+
+        run(&exprs, "a").expect("the assumptions hold")
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Parsed>> {
+        vec![
+            Assumption::new("wire `a` is driven", |exprs| {
+                exprs
+                    .iter()
+                    .any(|expr| expr.destination == Variable::from_str("a"))
+            }),
+            Assumption::new("every wire is driven exactly once", |exprs| {
+                let mut driven: HashSet<&Variable> = HashSet::new();
+                exprs.iter().all(|expr| driven.insert(&expr.destination))
+                    && exprs
+                        .iter()
+                        .flat_map(reads)
+                        .all(|name| driven.contains(name))
+            }),
+            Assumption::new("the circuit has no loops", |exprs| settles(exprs)),
+            Assumption::new("every shift is by a number below 16", |exprs| {
+                exprs
+                    .iter()
+                    .filter(|expr| matches!(expr.operator, Operator::LSHIFT | Operator::RSHIFT))
+                    .all(|expr| matches!(expr.operands.get(1), Some(Operand::LIT(by)) if *by < 16))
+            }),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        // NOTE: AND and the shifts can still hide `b` from `a`, so keep going until
+        // overriding `b` gives part 2 something new to find
+        loop {
+            let text = circuit(rng, 340 * scale);
+            let exprs = Self::parse(&Input::new(&text)).expect("generated circuits parse");
+            if Self::part1(&exprs) != Self::part2(&exprs) {
+                return Some(text);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_wires() {
+        let input = Input::new(include_str!("../../../examples/2015/7/1.txt"));
+        let exprs = Day7::parse(&input).unwrap();
+
+        for (wire, signal) in [
+            ("d", 72),
+            ("e", 507),
+            ("f", 492),
+            ("g", 114),
+            ("h", 65412),
+            ("i", 65079),
+            ("x", 123),
+            ("y", 456),
+        ] {
+            assert_eq!(run(&exprs, wire), Ok(signal), "wire {}", wire);
+        }
+    }
+
+    fn broken(text: &str) -> Vec<&'static str> {
+        let exprs = Day7::parse(&Input::new(text)).unwrap();
+        Day7::assumptions()
+            .into_iter()
+            .filter(|assumption| !(assumption.holds)(&exprs))
+            .map(|assumption| assumption.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_assumptions() {
+        assert!(broken("1 -> b\nb LSHIFT 2 -> a").is_empty());
+        assert_eq!(broken("1 -> b"), ["wire `a` is driven"]);
+        assert_eq!(
+            broken("1 -> a\n2 -> a"),
+            ["every wire is driven exactly once"]
+        );
+        assert_eq!(broken("b -> a\na -> b"), ["the circuit has no loops"]);
+        assert_eq!(
+            broken("1 -> b\nb LSHIFT 16 -> a"),
+            ["every shift is by a number below 16"]
+        );
+    }
+
+    #[test]
+    fn test_run_stops_on_loops() {
+        let exprs = Day7::parse(&Input::new("b -> a\na -> b")).unwrap();
+        assert!(run(&exprs, "a").is_err());
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day7);
@@ -0,0 +1,84 @@
+use aoc_core::{AocError, Input, Rng, Solution};
+use regex::Regex;
+use tracing::{debug, trace};
+
+fn part_1(lines: Vec<&str>) -> usize {
+    let total_string_code_length: usize = lines.iter().map(|line| line.len()).sum();
+
+    let re = Regex::new("(\\\\x[\\da-f][\\da-f]|\\\\.)").unwrap();
+
+    let diff: usize = lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            trace!(line = i, text = line);
+            re.captures_iter(line)
+                .map(|special_chars| {
+                    let escape = special_chars.get_match();
+                    trace!(line = i, escape = escape.as_str());
+                    escape.len() - 1
+                })
+                .sum::<usize>()
+                + 2
+        })
+        .sum();
+
+    debug!(
+        code = total_string_code_length,
+        memory = total_string_code_length - diff
+    );
+
+    diff
+}
+
+fn part_2(lines: Vec<&str>) -> usize {
+    let increase: usize = lines
+        .iter()
+        .map(|line| line.chars().filter(|&c| c == '\"' || c == '\\').count())
+        .sum();
+
+    lines.len() * 2 + increase
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 8;
+
+    type Parsed = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        Ok(input.lines().map(|line| line.to_owned()).collect())
+    }
+
+    fn part1(lines: &Self::Parsed) -> Self::Part1 {
+        part_1(lines.iter().map(|line| line.as_str()).collect())
+    }
+
+    fn part2(lines: &Self::Parsed) -> Self::Part2 {
+        part_2(lines.iter().map(|line| line.as_str()).collect())
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let lines: Vec<String> = (0..300 * scale)
+            .map(|_| {
+                let chars: String = (0..rng.range(0..=30))
+                    .map(|_| match rng.below(10) {
+                        0 => String::from("\\\\"),
+                        1 => String::from("\\\""),
+                        2 => format!("\\x{:02x}", rng.below(256)),
+                        _ => rng.word(1),
+                    })
+                    .collect();
+                format!("\"{}\"\n", chars)
+            })
+            .collect();
+        Some(lines.concat())
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day8);
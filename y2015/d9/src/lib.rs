@@ -0,0 +1,205 @@
+use std::collections::HashSet;
+
+use aoc_core::{
+    AocError, Assumption, Input, Rng, Solution,
+    error::{number, split_once},
+};
+use tracing::trace;
+
+/// The shortest and longest routes that visit every place once, built up
+/// over sets of places (Held-Karp) rather than trying every order.
+fn shortest_and_longest(routes: &[(String, String, usize)]) -> Option<(usize, usize)> {
+    let mut places: Vec<&str> = routes
+        .iter()
+        .flat_map(|(a, b, _)| [a.as_str(), b.as_str()])
+        .collect();
+    places.sort();
+    places.dedup();
+    let count = places.len();
+    let index = |place: &str| places.binary_search(&place).unwrap();
+
+    let mut distances = vec![vec![None; count]; count];
+    for (a, b, distance) in routes {
+        let (a, b) = (index(a), index(b));
+        distances[a][b] = Some(*distance);
+        distances[b][a] = Some(*distance);
+    }
+
+    // NOTE: best[visited][last] is the shortest and longest way to see the set `visited`
+    let mut best: Vec<Vec<Option<(usize, usize)>>> = vec![vec![None; count]; 1 << count];
+    for start in 0..count {
+        best[1 << start][start] = Some((0, 0));
+    }
+    for visited in 1..1usize << count {
+        for last in 0..count {
+            let Some((shortest, longest)) = best[visited][last] else {
+                continue;
+            };
+            for next in (0..count).filter(|next| visited & (1 << next) == 0) {
+                let Some(distance) = distances[last][next] else {
+                    continue;
+                };
+                let (shortest, longest) = (shortest + distance, longest + distance);
+                let entry = &mut best[visited | (1 << next)][next];
+                *entry = Some(entry.map_or((shortest, longest), |(s, l)| {
+                    (s.min(shortest), l.max(longest))
+                }));
+            }
+        }
+    }
+
+    trace!(places = count, "searched");
+
+    best[(1 << count) - 1]
+        .iter()
+        .flatten()
+        .copied()
+        .reduce(|(s1, l1), (s2, l2)| (s1.min(s2), l1.max(l2)))
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<(String, String, usize)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        input
+            .split_pairs(" = ")
+            .map(|pair| {
+                let (nodes, distance) = pair?;
+                let (a, b) = split_once(nodes, " to ")?;
+
+                Ok((a.to_string(), b.to_string(), number(distance)?))
+            })
+            .collect()
+    }
+
+    fn part1(routes: &Self::Parsed) -> Self::Part1 {
+        shortest_and_longest(routes).unwrap().0
+    }
+
+    fn part2(routes: &Self::Parsed) -> Self::Part2 {
+        shortest_and_longest(routes).unwrap().1
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Parsed>> {
+        vec![
+            Assumption::new("there is at least one route", |routes| !routes.is_empty()),
+            // NOTE: then any order of the places is a trip, so there's always one to find
+            Assumption::new("every pair of places has a distance", |routes| {
+                let places: HashSet<&str> = routes
+                    .iter()
+                    .flat_map(|(a, b, _)| [a.as_str(), b.as_str()])
+                    .collect();
+                let pairs: HashSet<(&str, &str)> = routes
+                    .iter()
+                    .flat_map(|(a, b, _)| [(a.as_str(), b.as_str()), (b.as_str(), a.as_str())])
+                    .collect();
+                places
+                    .iter()
+                    .all(|a| places.iter().all(|b| a == b || pairs.contains(&(*a, *b))))
+            }),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        // NOTE: the search doubles with every city, so each step of scale adds one
+        let cities = rng.names(7 + scale);
+        let mut lines = Vec::new();
+        for (i, a) in cities.iter().enumerate() {
+            for b in &cities[i + 1..] {
+                lines.push(format!("{} to {} = {}\n", a, b, rng.range(10..=150)));
+            }
+        }
+        Some(lines.concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use aoc_core::diff::Differential;
+    use itertools::Itertools;
+    use tracing::debug;
+
+    use super::*;
+
+    fn path_value(path: &Vec<&str>, edges: &HashMap<(&str, &str), usize>) -> Option<usize> {
+        let mut prev: &str = path.first().unwrap();
+        let mut total: usize = 0;
+
+        for node in path.iter().skip(1) {
+            total += edges
+                .get(&(prev, node))
+                .or_else(|| edges.get(&(node, prev)))?;
+            prev = node
+        }
+
+        trace!(?path, total, "route");
+
+        Some(total)
+    }
+
+    fn route_lengths(routes: &[(String, String, usize)]) -> Vec<usize> {
+        let edges: HashMap<(&str, &str), usize> = routes
+            .iter()
+            .map(|(a, b, distance)| ((a.as_str(), b.as_str()), *distance))
+            .collect();
+
+        debug!(?edges);
+
+        let mut nodes: HashSet<&str> = HashSet::new();
+        for (a, b) in edges.keys() {
+            nodes.insert(a);
+            nodes.insert(b);
+        }
+
+        debug!(?nodes);
+
+        let nodes_len = nodes.len();
+        nodes
+            .into_iter()
+            .permutations(nodes_len)
+            .inspect(|path| trace!(?path, "permutation"))
+            .filter_map(|path| path_value(&path, &edges))
+            .collect()
+    }
+
+    #[test]
+    fn test_search_agrees() {
+        Differential::new(
+            |routes: &Vec<(String, String, usize)>| {
+                let lengths = route_lengths(routes);
+                Some((*lengths.iter().min()?, *lengths.iter().max()?))
+            },
+            |routes: &Vec<(String, String, usize)>| shortest_and_longest(routes),
+        )
+        .valid(|routes| !routes.is_empty())
+        .check(|rng| {
+            let count = rng.range(2..=6) as usize;
+            let places = rng.names(count);
+            let mut routes = Vec::new();
+            for (i, a) in places.iter().enumerate() {
+                // NOTE: leave some out, as both have to cope with places that aren't linked
+                for b in &places[i + 1..] {
+                    if rng.chance(0.8) {
+                        routes.push((a.clone(), b.clone(), rng.range(1..=100) as usize));
+                    }
+                }
+            }
+            if routes.is_empty() {
+                routes.push((places[0].clone(), places[1].clone(), 1));
+            }
+            routes
+        });
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day9);
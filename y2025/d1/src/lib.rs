@@ -0,0 +1,220 @@
+use aoc_core::{AocError, Input, Rng, Solution, error::number};
+
+pub enum Rotation {
+    R(u64),
+    L(u64),
+}
+
+#[derive(Debug)]
+struct Dial {
+    pub pointer: u64,
+    pub zero_count: u64,
+}
+
+impl Dial {
+    fn rotate(self, rotation: &Rotation) -> Self {
+        let mut pointer: i64 = self.pointer as i64;
+        let mut zero_count = self.zero_count;
+        match rotation {
+            Rotation::R(n) => {
+                pointer += *n as i64;
+            }
+            Rotation::L(n) => {
+                pointer -= *n as i64;
+            }
+        }
+        let mod_pointer = if pointer < 0 {
+            100 + (pointer % 100)
+        } else {
+            pointer % 100
+        };
+
+        if mod_pointer == 0 {
+            zero_count += 1;
+        }
+        Self {
+            pointer: mod_pointer as u64,
+            zero_count,
+        }
+    }
+
+    /// Turns the dial a click at a time, as a reference for [`Dial::sweep`].
+    #[cfg(test)]
+    fn iter_rotate(self, rotation: &Rotation) -> Self {
+        match rotation {
+            Rotation::R(n) => (0..*n).fold(self, |acc, _| acc.rotate(&Rotation::R(1))),
+            Rotation::L(n) => (0..*n).fold(self, |acc, _| acc.rotate(&Rotation::L(1))),
+        }
+    }
+
+    /// Counts every click that lands on zero, working it out directly rather
+    /// than a click at a time.
+    fn sweep(self, rotation: &Rotation) -> Self {
+        let (pointer, zeros) = match rotation {
+            Rotation::R(n) => ((self.pointer + n) % 100, (self.pointer + n) / 100),
+            Rotation::L(n) => {
+                // NOTE: the first zero is `pointer` clicks away, or a whole turn if it's on one
+                let first = if self.pointer == 0 { 100 } else { self.pointer };
+                let zeros = if *n >= first {
+                    (n - first) / 100 + 1
+                } else {
+                    0
+                };
+                ((self.pointer + 100 - n % 100) % 100, zeros)
+            }
+        };
+
+        Self {
+            pointer,
+            zero_count: self.zero_count + zeros,
+        }
+    }
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Self {
+            pointer: 50,
+            zero_count: 0,
+        }
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<Rotation>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        input
+            .lines()
+            .map(|l| {
+                if let Some(distance) = l.strip_prefix('L') {
+                    Ok(Rotation::L(number(distance)?))
+                } else if let Some(distance) = l.strip_prefix('R') {
+                    Ok(Rotation::R(number(distance)?))
+                } else {
+                    let first = l.chars().next().map_or("", |c| &l[..c.len_utf8()]);
+                    Err(AocError::new(first, "`L` or `R`"))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(rotations: &Self::Parsed) -> Self::Part1 {
+        rotations
+            .iter()
+            .fold(Dial::default(), |acc, r| acc.rotate(r))
+            .zero_count
+    }
+
+    fn part2(rotations: &Self::Parsed) -> Self::Part2 {
+        rotations
+            .iter()
+            .fold(Dial::default(), |acc, r| acc.sweep(r))
+            .zero_count
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let lines: Vec<String> = (0..4000 * scale)
+            .map(|_| format!("{}{}\n", rng.pick(&['L', 'R']), rng.range(1..=999)))
+            .collect();
+        Some(lines.concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::diff::Differential;
+
+    use super::*;
+
+    fn turn_all(moves: &[(bool, u64)], turn: fn(Dial, &Rotation) -> Dial) -> (u64, u64) {
+        let dial = moves.iter().fold(Dial::default(), |dial, (right, n)| {
+            turn(
+                dial,
+                &if *right {
+                    Rotation::R(*n)
+                } else {
+                    Rotation::L(*n)
+                },
+            )
+        });
+        (dial.pointer, dial.zero_count)
+    }
+
+    #[test]
+    fn test_sweep_agrees() {
+        Differential::new(
+            |moves: &Vec<(bool, u64)>| turn_all(moves, Dial::iter_rotate),
+            |moves: &Vec<(bool, u64)>| turn_all(moves, Dial::sweep),
+        )
+        .check(|rng| {
+            (0..rng.index(10))
+                .map(|_| (rng.chance(0.5), rng.below(350)))
+                .collect()
+        });
+    }
+
+    #[test]
+    fn test_default() {
+        let dial = Dial::default();
+
+        assert_eq!(dial.pointer, 50);
+        assert_eq!(dial.zero_count, 0);
+    }
+
+    #[test]
+    fn test_rot_right() {
+        let mut dial = Dial::default();
+
+        dial = dial.iter_rotate(&Rotation::R(1));
+        assert_eq!(dial.pointer, 51);
+        assert_eq!(dial.zero_count, 0);
+
+        dial = dial.iter_rotate(&Rotation::R(48));
+        assert_eq!(dial.pointer, 99);
+        assert_eq!(dial.zero_count, 0);
+
+        dial = dial.iter_rotate(&Rotation::R(1));
+        assert_eq!(dial.pointer, 0);
+        assert_eq!(dial.zero_count, 1);
+
+        dial = dial.iter_rotate(&Rotation::R(101));
+        assert_eq!(dial.pointer, 1);
+        assert_eq!(dial.zero_count, 2);
+
+        dial = dial.iter_rotate(&Rotation::R(201));
+        assert_eq!(dial.pointer, 2);
+        assert_eq!(dial.zero_count, 4);
+    }
+
+    #[test]
+    fn test_rot_left() {
+        let mut dial = Dial::default();
+
+        dial = dial.iter_rotate(&Rotation::L(1));
+        assert_eq!(dial.pointer, 49);
+        assert_eq!(dial.zero_count, 0);
+
+        dial = dial.iter_rotate(&Rotation::L(49));
+        assert_eq!(dial.pointer, 0);
+        assert_eq!(dial.zero_count, 1);
+
+        dial = dial.iter_rotate(&Rotation::L(101));
+        assert_eq!(dial.pointer, 99);
+        assert_eq!(dial.zero_count, 2);
+
+        dial = dial.iter_rotate(&Rotation::L(201));
+        assert_eq!(dial.pointer, 98);
+        assert_eq!(dial.zero_count, 4);
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day1);
@@ -0,0 +1,421 @@
+use std::collections::VecDeque;
+
+use aoc_core::{AocError, Assumption, Input, Rng, Solution, error::number};
+use good_lp::{
+    Constraint, Expression, ProblemVariables, Solution as LpSolution, SolverModel, Variable,
+    constraint, default_solver, variable, variables,
+};
+use tracing::{debug, trace};
+
+#[derive(Debug)]
+pub struct Machine {
+    lights: Vec<bool>,
+    buttons: Vec<Vec<u64>>,
+    joltages: Vec<u64>,
+}
+
+/// The numbers in `text` between `open` and `close`, separated by commas.
+fn numbers(text: &str, open: char, close: char) -> Result<Vec<u64>, AocError> {
+    let inner = text
+        .strip_prefix(open)
+        .and_then(|rest| rest.strip_suffix(close))
+        .ok_or_else(|| AocError::new(text, format!("a list in `{}{}`", open, close)))?;
+
+    inner.split(',').map(number).collect()
+}
+
+impl TryFrom<&str> for Machine {
+    type Error = AocError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut parts = value.split(' ');
+        let lights = parts
+            .next()
+            .and_then(|part| part.strip_prefix('[')?.strip_suffix(']'))
+            .ok_or_else(|| AocError::new(value, "lights in `[]`"))?;
+        let lights: Vec<bool> = lights
+            .char_indices()
+            .map(|(i, c)| match c {
+                '.' => Ok(false),
+                '#' => Ok(true),
+                _ => Err(AocError::new(&lights[i..i + c.len_utf8()], "`.` or `#`")),
+            })
+            .collect::<Result<_, _>>()?;
+
+        let joltages_text = parts
+            .next_back()
+            .ok_or_else(|| AocError::after(value, "joltages in `{}`"))?;
+        let joltages = numbers(joltages_text, '{', '}')?;
+        if joltages.len() != lights.len() {
+            return Err(AocError::new(joltages_text, "a joltage for every light"));
+        }
+
+        let buttons = parts
+            .map(|button| {
+                let wired = numbers(button, '(', ')')?;
+                if wired.iter().any(|light| *light as usize >= lights.len()) {
+                    return Err(AocError::new(button, "a button wired to existing lights"));
+                }
+                Ok(wired)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            lights,
+            buttons,
+            joltages,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct QueuedLights {
+    depth: u64,
+    lights: Vec<bool>,
+}
+
+fn fewest_buttons_lights(lights: Vec<bool>, buttons: Vec<Vec<u64>>) -> u64 {
+    let mut queue: VecDeque<QueuedLights> = VecDeque::new();
+    queue.push_back(QueuedLights { depth: 0, lights });
+
+    loop {
+        let item = queue.pop_front().unwrap();
+        // NOTE: check if all the lights are off:
+        // by going from the required state to the starting all off state
+        if item.lights.iter().all(|l| !*l) {
+            return item.depth;
+        } else {
+            buttons.iter().for_each(|button| {
+                let mut lights = item.lights.to_owned();
+                button.iter().for_each(|i| {
+                    *lights.get_mut(*i as usize).unwrap() = !lights.get(*i as usize).unwrap()
+                });
+                queue.push_back(QueuedLights {
+                    depth: item.depth + 1,
+                    lights,
+                });
+            });
+        }
+    }
+}
+
+/// Whether some presses of `buttons` leave exactly `lights` on.
+fn can_light(lights: &[bool], buttons: &[Vec<u64>]) -> bool {
+    // NOTE: pressing a button twice undoes it, so this solves for which buttons to press
+    // once, by elimination with xor over a row per light
+    let mut rows: Vec<Vec<bool>> = lights
+        .iter()
+        .enumerate()
+        .map(|(i, on)| {
+            let mut row: Vec<bool> = buttons
+                .iter()
+                .map(|button| button.contains(&(i as u64)))
+                .collect();
+            row.push(*on);
+            row
+        })
+        .collect();
+
+    let mut solved = 0;
+    for column in 0..buttons.len() {
+        let Some(found) = (solved..rows.len()).find(|&r| rows[r][column]) else {
+            continue;
+        };
+        rows.swap(solved, found);
+        let pivot = rows[solved].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != solved && row[column] {
+                row.iter_mut().zip(&pivot).for_each(|(cell, p)| *cell ^= p);
+            }
+        }
+        solved += 1;
+    }
+
+    // NOTE: a light no button is left wired to can't be changed, so it has to be off
+    rows.iter()
+        .all(|row| !row[buttons.len()] || row[..buttons.len()].iter().any(|c| *c))
+}
+
+struct JoltageSet {
+    required: u64,
+    acheived: Expression,
+}
+
+struct ButtonCounterProblem {
+    vars: ProblemVariables,
+    joltages: Vec<JoltageSet>,
+    total_presses: Expression,
+}
+
+impl ButtonCounterProblem {
+    fn new(joltages: &[u64]) -> Self {
+        Self {
+            vars: variables! {},
+            joltages: joltages
+                .iter()
+                .map(|joltage| JoltageSet {
+                    required: *joltage,
+                    acheived: 0.into(),
+                })
+                .collect(),
+            total_presses: 0.into(),
+        }
+    }
+
+    fn add_button(&mut self, button: Vec<u64>) -> Variable {
+        let presses = self.vars.add(variable().min(0).integer());
+        self.total_presses += presses;
+        button
+            .into_iter()
+            .for_each(|b| self.joltages.get_mut(b as usize).unwrap().acheived += presses);
+        presses
+    }
+
+    /// `None` if a joltage is too big for the solver.
+    fn constraints(joltages: Vec<JoltageSet>) -> Option<Vec<Constraint>> {
+        let mut constraints = Vec::with_capacity(joltages.len());
+        for joltage in joltages {
+            let required = u32::try_from(joltage.required).ok()?;
+            constraints.push(constraint!(joltage.acheived == required));
+        }
+        Some(constraints)
+    }
+
+    /// `None` if no number of presses reaches the joltages.
+    fn least_presses(self) -> Option<impl LpSolution> {
+        let objective = self.total_presses;
+        self.vars
+            .minimise(objective)
+            .using(default_solver)
+            .with_all(Self::constraints(self.joltages)?)
+            .solve()
+            .ok()
+    }
+}
+
+fn fewest_buttons_counters(joltages: Vec<u64>, buttons: Vec<Vec<u64>>) -> Option<u64> {
+    let mut button_problem = ButtonCounterProblem::new(&joltages);
+    let presses: Vec<Variable> = buttons
+        .into_iter()
+        .map(|b| button_problem.add_button(b))
+        .collect();
+    let solution = button_problem.least_presses()?;
+    Some(
+        presses
+            .into_iter()
+            .map(|p| {
+                let f = solution.value(p);
+                trace!(presses = f);
+                f.round() as u64
+            })
+            .sum(),
+    )
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 10;
+
+    type Parsed = Vec<Machine>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        input.lines().map(Machine::try_from).collect()
+    }
+
+    fn part1(machines: &Self::Parsed) -> Self::Part1 {
+        machines
+            .iter()
+            .map(|machine| fewest_buttons_lights(machine.lights.clone(), machine.buttons.clone()))
+            .sum()
+    }
+
+    fn part2(machines: &Self::Parsed) -> Self::Part2 {
+        let machines_len = machines.len();
+
+        machines
+            .iter()
+            .enumerate()
+            .map(|(i, machine)| {
+                let res =
+                    fewest_buttons_counters(machine.joltages.clone(), machine.buttons.clone())
+                        .expect("the assumptions hold");
+                debug!(
+                    machine = i + 1,
+                    of = machines_len,
+                    presses = res,
+                    "finished"
+                );
+                res
+            })
+            .sum()
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Parsed>> {
+        vec![
+            Assumption::new("every machine's buttons can light it", |machines| {
+                machines
+                    .iter()
+                    .all(|machine| can_light(&machine.lights, &machine.buttons))
+            }),
+            Assumption::new("every joltage fits in a u32", |machines| {
+                machines
+                    .iter()
+                    .flat_map(|machine| &machine.joltages)
+                    .all(|joltage| u32::try_from(*joltage).is_ok())
+            }),
+            // NOTE: this solves part 2 over again, but it only takes milliseconds
+            Assumption::new(
+                "every machine's buttons can reach its joltages",
+                |machines| {
+                    machines.iter().all(|machine| {
+                        fewest_buttons_counters(machine.joltages.clone(), machine.buttons.clone())
+                            .is_some()
+                    })
+                },
+            ),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let machines: Vec<String> = (0..170 * scale)
+            .map(|_| {
+                let size = rng.range(4..=10) as usize;
+                let buttons: Vec<Vec<usize>> = (0..rng.range(3..=size as i64 + 3))
+                    .map(|_| {
+                        let mut wired: Vec<usize> = (0..size).collect();
+                        rng.shuffle(&mut wired);
+                        wired.truncate(rng.range(1..=size as i64 - 1) as usize);
+                        wired.sort();
+                        wired
+                    })
+                    .collect();
+
+                // NOTE: both targets come from presses that were really made, so every
+                // machine can be solved, and only a few presses light it so part 1's
+                // search stays shallow
+                let mut lights = vec![false; size];
+                for _ in 0..rng.range(1..=3) {
+                    for light in rng.pick(&buttons) {
+                        lights[*light] = !lights[*light];
+                    }
+                }
+                let mut joltages = vec![0; size];
+                for button in &buttons {
+                    let presses = rng.range(0..=20);
+                    for light in button {
+                        joltages[*light] += presses;
+                    }
+                }
+
+                let lights: String = lights
+                    .iter()
+                    .map(|on| if *on { '#' } else { '.' })
+                    .collect();
+                let buttons: Vec<String> = buttons
+                    .iter()
+                    .map(|button| {
+                        let wired: Vec<String> = button.iter().map(ToString::to_string).collect();
+                        format!("({})", wired.join(","))
+                    })
+                    .collect();
+                let joltages: Vec<String> = joltages.iter().map(ToString::to_string).collect();
+                format!(
+                    "[{}] {} {{{}}}\n",
+                    lights,
+                    buttons.join(" "),
+                    joltages.join(",")
+                )
+            })
+            .collect();
+        Some(machines.concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fewest_buttons() {
+        let res = fewest_buttons_lights(
+            vec![false, true, true, true, false, true],
+            vec![vec![0, 3, 4], vec![0, 1, 2, 4, 5]],
+        );
+
+        assert_eq!(res, 2);
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_lights() {
+        let err = Machine::try_from("[.#] (0) (2) {1,2}").unwrap_err();
+        assert_eq!(err.text, "(2)");
+
+        let err = Machine::try_from("[.#] (0) (1) {1,2,3}").unwrap_err();
+        assert_eq!(err.text, "{1,2,3}");
+    }
+
+    #[test]
+    fn test_can_light() {
+        assert!(can_light(
+            &[false, true, true, false],
+            &[
+                vec![3],
+                vec![1, 3],
+                vec![2],
+                vec![2, 3],
+                vec![0, 2],
+                vec![0, 1]
+            ],
+        ));
+        assert!(!can_light(&[true], &[]));
+        assert!(!can_light(&[true, false], &[vec![0, 1]]));
+    }
+
+    #[test]
+    fn test_fewest_counters_infeasible() {
+        assert_eq!(fewest_buttons_counters(vec![1, 2], vec![vec![0, 1]]), None);
+        assert_eq!(
+            fewest_buttons_counters(vec![3, 3], vec![vec![0, 1]]),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_assumptions_catch_unsolvable_machines() {
+        let broken = |line: &str| -> Vec<&str> {
+            let machines = vec![Machine::try_from(line).unwrap()];
+            Day10::assumptions()
+                .into_iter()
+                .filter(|assumption| !(assumption.holds)(&machines))
+                .map(|assumption| assumption.name)
+                .collect()
+        };
+
+        assert!(broken("[##] (0,1) {3,3}").is_empty());
+        assert_eq!(
+            broken("[#] {1}"),
+            [
+                "every machine's buttons can light it",
+                "every machine's buttons can reach its joltages"
+            ]
+        );
+        assert_eq!(
+            broken("[##] (0,1) {1,2}"),
+            ["every machine's buttons can reach its joltages"]
+        );
+        assert_eq!(
+            broken("[##] (0,1) {4294967296,4294967296}"),
+            [
+                "every joltage fits in a u32",
+                "every machine's buttons can reach its joltages"
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day10);
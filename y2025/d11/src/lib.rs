@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use aoc_core::{AocError, Input, Rng, Solution, error::split_once};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 11;
+
+    type Parsed = HashMap<String, Vec<String>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        input
+            .lines()
+            .map(|line| {
+                let (name, links) = split_once(line, ":")?;
+                let links: Vec<String> = links
+                    .split(' ')
+                    .filter(|link| !link.is_empty())
+                    .map(|link| link.to_owned())
+                    .collect();
+                Ok((name.to_owned(), links))
+            })
+            .collect()
+    }
+
+    fn part1(nodes: &Self::Parsed) -> Self::Part1 {
+        explore("you", "out", nodes, &mut HashMap::new())
+    }
+
+    fn part2(nodes: &Self::Parsed) -> Self::Part2 {
+        let path1 = explore("svr", "dac", nodes, &mut HashMap::new())
+            * explore("dac", "fft", nodes, &mut HashMap::new())
+            * explore("fft", "out", nodes, &mut HashMap::new());
+
+        let path2 = explore("svr", "fft", nodes, &mut HashMap::new())
+            * explore("fft", "dac", nodes, &mut HashMap::new())
+            * explore("dac", "out", nodes, &mut HashMap::new());
+
+        path1 + path2
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        // NOTE: devices are laid out in a line with every output further along it, so
+        // there are no loops, and each feeds the next so every route the parts count
+        // exists. Routes to `out` are counted while wiring to keep the answers in a u64
+        const MAX_PATHS: u64 = 1 << 50;
+        const SPECIAL: [&str; 5] = ["svr", "you", "dac", "fft", "out"];
+        let count = 600 * scale - SPECIAL.len();
+        let mut devices = rng.words(count, Rng::letters_for(count).max(3), &SPECIAL);
+        for name in ["you", "dac", "fft"] {
+            let at = rng.index(devices.len() + 1);
+            devices.insert(at, name.to_owned());
+        }
+        devices.insert(0, String::from("svr"));
+        devices.push(String::from("out"));
+
+        let last = devices.len() - 1;
+        let mut paths = vec![0u64; devices.len()];
+        paths[last] = 1;
+        let mut outputs: Vec<Vec<usize>> = vec![Vec::new(); last];
+        for i in (0..last).rev() {
+            outputs[i].push(i + 1);
+            paths[i] = paths[i + 1];
+            for _ in 0..rng.range(0..=3) {
+                let to = i + 1 + rng.index((last - i).min(30));
+                if !outputs[i].contains(&to) && paths[i] + paths[to] <= MAX_PATHS {
+                    outputs[i].push(to);
+                    paths[i] += paths[to];
+                }
+            }
+        }
+
+        let mut lines: Vec<String> = outputs
+            .iter()
+            .enumerate()
+            .map(|(i, outputs)| {
+                let names: Vec<&str> = outputs.iter().map(|to| devices[*to].as_str()).collect();
+                format!("{}: {}\n", devices[i], names.join(" "))
+            })
+            .collect();
+        rng.shuffle(&mut lines);
+        Some(lines.concat())
+    }
+}
+
+fn explore(
+    node: &str,
+    end_node: &str,
+    nodes: &HashMap<String, Vec<String>>,
+    cache: &mut HashMap<String, u64>,
+) -> u64 {
+    if let Some(res) = cache.get(node) {
+        *res
+    } else {
+        let res = if node == end_node {
+            1
+        } else {
+            match nodes.get(node) {
+                Some(next_vec) => next_vec
+                    .iter()
+                    .map(|next| explore(next, end_node, nodes, cache))
+                    .sum(),
+                None => 0,
+            }
+        };
+        cache.insert(node.to_owned(), res);
+        res
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day11);
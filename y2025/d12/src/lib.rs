@@ -0,0 +1,377 @@
+use aoc_core::{
+    Answer, AocError, Assumption, Input, Rng, Solution,
+    error::{number, split_once},
+};
+use std::collections::HashSet;
+
+use tracing::{debug, trace};
+
+#[derive(Debug, Clone, Copy)]
+struct Shape {
+    shape: [[bool; 3]; 3],
+}
+
+impl Shape {
+    fn area(&self) -> u64 {
+        self.shape
+            .iter()
+            .map(|row| row.iter().filter(|c| **c).count())
+            .sum::<usize>() as u64
+    }
+
+    /// Every distinct way the shape can be turned or flipped, as the cells it covers in
+    /// reading order.
+    fn orientations(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut shape = self.shape;
+        let mut orientations: Vec<Vec<(usize, usize)>> = Vec::with_capacity(8);
+        for _ in 0..2 {
+            for _ in 0..4 {
+                let cells = (0..3)
+                    .flat_map(|y| (0..3).map(move |x| (x, y)))
+                    .filter(|&(x, y)| shape[y][x])
+                    .collect();
+                if !orientations.contains(&cells) {
+                    orientations.push(cells);
+                }
+                shape = std::array::from_fn(|y| std::array::from_fn(|x| shape[2 - x][y]));
+            }
+            shape = shape.map(|mut row| {
+                row.reverse();
+                row
+            });
+        }
+        orientations
+    }
+}
+
+#[derive(Debug)]
+struct Area {
+    size: (u32, u32),
+    shapes: Vec<u8>,
+}
+
+impl Area {
+    fn area(&self) -> u64 {
+        self.size.0 as u64 * self.size.1 as u64
+    }
+}
+
+impl TryFrom<&str> for Shape {
+    type Error = AocError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut shape = [[false; 3]; 3];
+        let mut lines = value.lines();
+        for row in shape.iter_mut() {
+            let line = lines
+                .next()
+                .ok_or_else(|| AocError::after(value, "three rows in the shape"))?;
+            let mut chars = line.char_indices();
+            for cell in row.iter_mut() {
+                *cell = match chars.next() {
+                    Some((_, '#')) => true,
+                    Some((_, '.')) => false,
+                    Some((i, c)) => {
+                        return Err(AocError::new(&line[i..i + c.len_utf8()], "`#` or `.`"));
+                    }
+                    None => return Err(AocError::after(line, "three cells in the row")),
+                };
+            }
+            if let Some((i, _)) = chars.next() {
+                return Err(AocError::new(&line[i..], "only three cells in the row"));
+            }
+        }
+        if let Some(line) = lines.next() {
+            return Err(AocError::new(line, "only three rows in the shape"));
+        }
+        if shape.iter().flatten().all(|cell| !cell) {
+            return Err(AocError::new(value, "at least one `#` in the shape"));
+        }
+        Ok(Self { shape })
+    }
+}
+
+struct Packing<'a> {
+    width: usize,
+    filled: Vec<bool>,
+    orientations: &'a [Vec<Vec<(usize, usize)>>],
+    counts: Vec<u8>,
+    /// The presents left and the cells from the first empty one on, for every packing
+    /// already known not to work.
+    failed: HashSet<(Vec<u8>, Vec<bool>)>,
+}
+
+impl Packing<'_> {
+    /// Fills the region from `from` onwards in reading order, leaving at most `slack`
+    /// cells empty. The first empty cell is either covered by the first cell of some
+    /// present, or left empty for good.
+    fn pack(&mut self, from: usize, slack: u64) -> bool {
+        if self.counts.iter().all(|&count| count == 0) {
+            return true;
+        }
+        let Some(at) = (from..self.filled.len()).find(|&i| !self.filled[i]) else {
+            return false;
+        };
+        // NOTE: nothing before `at` can be covered any more, so the rest decides it
+        let state = (self.counts.clone(), self.filled[at..].to_vec());
+        if self.failed.contains(&state) {
+            return false;
+        }
+        let fits = self.place(at, slack);
+        if !fits {
+            self.failed.insert(state);
+        }
+        fits
+    }
+
+    fn place(&mut self, at: usize, slack: u64) -> bool {
+        let (ax, ay) = (at % self.width, at / self.width);
+        let height = self.filled.len() / self.width;
+
+        let orientations = self.orientations;
+        for (shape, cells) in orientations
+            .iter()
+            .enumerate()
+            .flat_map(|(shape, cells)| cells.iter().map(move |cells| (shape, cells)))
+        {
+            if self.counts[shape] == 0 {
+                continue;
+            }
+            let (first_x, first_y) = cells[0];
+            let placed: Option<Vec<usize>> = cells
+                .iter()
+                .map(|&(x, y)| {
+                    let (x, y) = ((ax + x).checked_sub(first_x)?, ay + y - first_y);
+                    (x < self.width && y < height && !self.filled[y * self.width + x])
+                        .then_some(y * self.width + x)
+                })
+                .collect();
+            let Some(placed) = placed else {
+                continue;
+            };
+
+            placed.iter().for_each(|&i| self.filled[i] = true);
+            self.counts[shape] -= 1;
+            let fits = self.pack(at + 1, slack);
+            self.counts[shape] += 1;
+            placed.iter().for_each(|&i| self.filled[i] = false);
+            if fits {
+                return true;
+            }
+        }
+
+        if slack == 0 {
+            return false;
+        }
+        self.filled[at] = true;
+        let fits = self.pack(at + 1, slack - 1);
+        self.filled[at] = false;
+        fits
+    }
+}
+
+fn fit_shapes(area: &Area, shapes: &[Shape]) -> bool {
+    let (width, height) = (area.size.0 as usize, area.size.1 as usize);
+    let presents: usize = area.shapes.iter().map(|&count| count as usize).sum();
+    // NOTE: every present fits in a 3x3 square of its own, so there's nothing to search
+    if (width / 3) * (height / 3) >= presents {
+        return true;
+    }
+    let needed: u64 = area
+        .shapes
+        .iter()
+        .zip(shapes)
+        .map(|(count, shape)| *count as u64 * shape.area())
+        .sum();
+    if needed > area.area() {
+        return false;
+    }
+
+    let orientations: Vec<_> = shapes.iter().map(Shape::orientations).collect();
+    Packing {
+        width,
+        filled: vec![false; width * height],
+        orientations: &orientations,
+        counts: area.shapes.clone(),
+        failed: HashSet::new(),
+    }
+    .pack(0, area.area() - needed)
+}
+
+#[derive(Debug)]
+pub struct Presents {
+    shapes: Vec<Shape>,
+    areas: Vec<Area>,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 12;
+
+    type Parsed = Presents;
+    type Part1 = usize;
+    type Part2 = Answer;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        // NOTE: both inputs have 6 shapes
+        let mut shapes: Vec<Shape> = Vec::with_capacity(6);
+        let mut areas: Vec<Area> = Vec::new();
+
+        for section in input.paragraphs() {
+            if section.contains('x') {
+                // handle area
+                areas = section
+                    .split_pairs(": ")
+                    .map(|pair| {
+                        let (size, counts) = pair?;
+                        let (x, y) = split_once(size, "x")?;
+                        let shapes = counts.split(' ').map(number).collect::<Result<_, _>>()?;
+
+                        Ok(Area {
+                            shapes,
+                            size: (number(x)?, number(y)?),
+                        })
+                    })
+                    .collect::<Result<_, AocError>>()?;
+            } else {
+                // shapes are discovered in order, so we can ignore the index line
+                let (_, rows) = split_once(&section, "\n")?;
+                shapes.push(Shape::try_from(rows)?);
+            }
+        }
+
+        debug!(?shapes);
+        trace!(?areas);
+
+        Ok(Presents { shapes, areas })
+    }
+
+    fn part1(presents: &Self::Parsed) -> Self::Part1 {
+        debug!(areas = presents.areas.len(), "starting");
+
+        let res: usize = presents
+            .areas
+            .iter()
+            .filter(|area| fit_shapes(area, &presents.shapes))
+            .count();
+
+        debug!(areas = res, "fit");
+
+        res
+    }
+
+    fn part2(_: &Self::Parsed) -> Self::Part2 {
+        Answer::Unsolved
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Parsed>> {
+        // NOTE: each shape is checked to be 3x3 as it's parsed
+        vec![
+            Assumption::new("there are six shapes", |presents| {
+                presents.shapes.len() == 6
+            }),
+            Assumption::new("every region lists one count per shape", |presents| {
+                presents
+                    .areas
+                    .iter()
+                    .all(|area| area.shapes.len() == presents.shapes.len())
+            }),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let shapes: Vec<String> = (0..6)
+            .map(|_| {
+                // NOTE: the middle is always filled so no shape is empty
+                let rows: Vec<String> = (0..3)
+                    .map(|y| {
+                        (0..3)
+                            .map(|x| match (x, y) {
+                                (1, 1) => '#',
+                                _ if rng.chance(0.6) => '#',
+                                _ => '.',
+                            })
+                            .collect()
+                    })
+                    .collect();
+                rows.join("\n")
+            })
+            .collect();
+        let cells: Vec<i64> = shapes
+            .iter()
+            .map(|shape| shape.matches('#').count() as i64)
+            .collect();
+        let mut sections: Vec<String> = shapes
+            .iter()
+            .enumerate()
+            .map(|(i, shape)| format!("{}:\n{}", i, shape))
+            .collect();
+        let regions: Vec<String> = (0..1000 * scale)
+            .map(|_| {
+                let (width, height) = (rng.range(35..=50), rng.range(35..=50));
+                let counts: Vec<i64> = if rng.chance(0.25) {
+                    // NOTE: more presents than there's room for, so some regions don't fit
+                    let mut counts = vec![0; 6];
+                    let mut needed = 0;
+                    while needed <= width * height {
+                        let i = rng.index(6);
+                        counts[i] += 1;
+                        needed += cells[i];
+                    }
+                    counts
+                } else {
+                    (0..6).map(|_| rng.range(0..=width * height / 45)).collect()
+                };
+                let counts: Vec<String> = counts.iter().map(ToString::to_string).collect();
+                format!("{}x{}: {}", width, height, counts.join(" "))
+            })
+            .collect();
+        sections.push(regions.join("\n"));
+        Some(sections.join("\n\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shape_is_three_by_three() {
+        assert!(Shape::try_from("###\n#..\n###").is_ok());
+
+        let err = Shape::try_from("###\n#..#\n###").unwrap_err();
+        assert_eq!(err.text, "#");
+        let err = Shape::try_from("###\n#..\n###\n..#").unwrap_err();
+        assert_eq!(err.text, "..#");
+    }
+
+    #[test]
+    fn test_shape_is_not_empty() {
+        let err = Shape::try_from("...\n...\n...").unwrap_err();
+        assert_eq!(err.text, "...\n...\n...");
+    }
+
+    #[test]
+    fn test_area_of_large_region() {
+        let area = Area {
+            size: (u32::MAX, 2),
+            shapes: vec![],
+        };
+        assert_eq!(area.area(), u32::MAX as u64 * 2);
+    }
+
+    #[test]
+    fn test_orientations() {
+        let square = Shape::try_from("###\n###\n###").unwrap();
+        assert_eq!(square.orientations().len(), 1);
+        let corner = Shape::try_from("##.\n#..\n...").unwrap();
+        assert_eq!(corner.orientations().len(), 4);
+        let hook = Shape::try_from("###\n#..\n...").unwrap();
+        assert_eq!(hook.orientations().len(), 8);
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day12);
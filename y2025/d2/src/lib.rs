@@ -0,0 +1,104 @@
+use std::{iter::repeat_n, ops::RangeInclusive};
+
+use aoc_core::{AocError, Input, Rng, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    // NOTE: the ranges in the input include their end
+    type Parsed = Vec<RangeInclusive<usize>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        let ints = input.ints::<usize>()?;
+        if !ints.len().is_multiple_of(2) {
+            return Err(AocError::after(input, "the end of the last range"));
+        }
+
+        Ok(ints
+            .chunks_exact(2)
+            .map(|sides| sides[0]..=sides[1])
+            .collect())
+    }
+
+    fn part1(ranges: &Self::Parsed) -> Self::Part1 {
+        sum_matching(ranges, |s| is_repeat(s.to_owned()))
+    }
+
+    fn part2(ranges: &Self::Parsed) -> Self::Part2 {
+        sum_matching(ranges, is_repeating)
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let ranges: Vec<String> = (0..35 * scale)
+            .map(|_| {
+                let digits = rng.range(1..=10) as u32;
+                let start = rng.range(1..=10i64.pow(digits) - 1);
+                format!("{}-{}", start, start + rng.range(0..=100_000))
+            })
+            .collect();
+        Some(ranges.join(","))
+    }
+}
+
+fn sum_matching(ranges: &[RangeInclusive<usize>], matches: impl Fn(&str) -> bool) -> u64 {
+    ranges
+        .iter()
+        .map(|r| {
+            r.clone()
+                .filter_map(|i| {
+                    let as_string = format!("{}", i);
+
+                    if matches(&as_string) {
+                        Some(i as u64)
+                    } else {
+                        None
+                    }
+                })
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+fn is_repeat(s: String) -> bool {
+    let mut string = s.to_owned();
+    let half = string.split_off(s.len() / 2);
+    half == string
+}
+
+fn is_repeating(s: &str) -> bool {
+    (1..=s.len() / 2)
+        .map(|i| s.split_at(i).0)
+        .filter(|part| s.len().is_multiple_of(part.len()))
+        .any(|part| {
+            let repeats = s.len() / part.len();
+            repeats > 1 && repeat_n(part, repeats).collect::<String>() == s
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        assert!(!is_repeating("1"))
+    }
+
+    #[test]
+    fn test_11() {
+        assert!(is_repeating("11"))
+    }
+
+    #[test]
+    fn test_101() {
+        assert!(!is_repeating("101"))
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day2);
@@ -0,0 +1,179 @@
+use std::{array, collections::VecDeque};
+
+use aoc_core::{AocError, Assumption, Input, Rng, Solution};
+
+/// Picks each digit by scanning what's left, as a reference for [`bucket_select_n_from_k`].
+#[cfg(test)]
+fn insert_select_n_from_k(battery: &[u8], n: usize) -> u64 {
+    (0..n)
+        .fold((0usize, 0u64), |acc, i| {
+            let (start, v) = battery[acc.0..(battery.len() - (n - 1) + i)]
+                .iter()
+                .enumerate()
+                .reduce(|(acc_i, acc_v), (index, value)| {
+                    if value <= acc_v {
+                        (acc_i, acc_v)
+                    } else {
+                        (index, value)
+                    }
+                })
+                .unwrap();
+            (acc.0 + start + 1, acc.1 * 10 + *v as u64)
+        })
+        .1
+}
+
+fn bucket_select_n_from_k(battery: &[u8], n: usize) -> u64 {
+    // sort
+    let mut buckets: [VecDeque<(usize, u8)>; 9] =
+    // NOTE: there is no noticable performance decrease with this capacity
+        array::from_fn(|_| VecDeque::with_capacity(battery.len() / 9));
+    let mut battery_iter = battery.iter().enumerate();
+    battery_iter
+        .by_ref()
+        .take(battery.len() - n)
+        .for_each(|(i, b)| buckets[*b as usize - 1].push_back((i, *b)));
+
+    // select
+    (0..n)
+        .fold((0usize, 0u64), |acc, _| {
+            // push next battery into buckets to maintain buffer at end
+            let next_battery = battery_iter.next().unwrap();
+            buckets[*next_battery.1 as usize - 1].push_back((next_battery.0, *next_battery.1));
+
+            let mut selected_battery = None;
+
+            while selected_battery.is_none() {
+                let next = if let Some(item) = buckets[8].pop_front() {
+                    item
+                } else if let Some(item) = buckets[7].pop_front() {
+                    item
+                } else if let Some(item) = buckets[6].pop_front() {
+                    item
+                } else if let Some(item) = buckets[5].pop_front() {
+                    item
+                } else if let Some(item) = buckets[4].pop_front() {
+                    item
+                } else if let Some(item) = buckets[3].pop_front() {
+                    item
+                } else if let Some(item) = buckets[2].pop_front() {
+                    item
+                } else if let Some(item) = buckets[1].pop_front() {
+                    item
+                } else if let Some(item) = buckets[0].pop_front() {
+                    item
+                } else {
+                    unreachable!("someone did a silly");
+                };
+                if next.0 >= acc.0 {
+                    selected_battery = Some(next);
+                }
+            }
+            let selected_battery = selected_battery.expect("someone did a silly");
+
+            (selected_battery.0, acc.1 * 10 + selected_battery.1 as u64)
+        })
+        .1
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Parsed = Vec<Vec<u8>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        input
+            .lines()
+            .map(|line| {
+                line.char_indices()
+                    .map(|(i, c)| {
+                        c.to_digit(10)
+                            .map(|d| d as u8)
+                            .ok_or_else(|| AocError::new(&line[i..i + c.len_utf8()], "a digit"))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(batteries: &Self::Parsed) -> Self::Part1 {
+        batteries
+            .iter()
+            .map(|battery| bucket_select_n_from_k(battery, 2))
+            .sum()
+    }
+
+    fn part2(batteries: &Self::Parsed) -> Self::Part2 {
+        batteries
+            .iter()
+            .map(|battery| bucket_select_n_from_k(battery, 12))
+            .sum()
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Parsed>> {
+        vec![
+            Assumption::new("every bank has at least 12 batteries", |batteries| {
+                batteries.iter().all(|battery| battery.len() >= 12)
+            }),
+            Assumption::new("every battery is rated from 1 to 9", |batteries| {
+                batteries.iter().flatten().all(|b| (1..=9).contains(b))
+            }),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let banks: Vec<String> = (0..200 * scale)
+            .map(|_| {
+                let bank: String = (0..100)
+                    .map(|_| char::from_digit(rng.range(1..=9) as u32, 10).unwrap())
+                    .collect();
+                format!("{}\n", bank)
+            })
+            .collect();
+        Some(banks.concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::diff::Differential;
+
+    use super::*;
+
+    #[test]
+    fn test_char_to_u32() {
+        assert_eq!('5'.to_digit(10).unwrap(), 5)
+    }
+
+    #[test]
+    fn test_char_to_u64() {
+        assert_eq!('5'.to_digit(10).unwrap() as u64, 5)
+    }
+
+    #[test]
+    fn test_selects_agree() {
+        Differential::new(
+            |(battery, n): &(Vec<u8>, usize)| insert_select_n_from_k(battery, *n),
+            |(battery, n): &(Vec<u8>, usize)| bucket_select_n_from_k(battery, *n),
+        )
+        // NOTE: more than 19 digits won't fit in a u64
+        .valid(|(battery, n)| {
+            (1..=19.min(battery.len())).contains(n) && battery.iter().all(|d| (1..=9).contains(d))
+        })
+        .check(|rng| {
+            let n = *rng.pick(&[1, 2, 12]);
+            let battery = (0..n + rng.index(20))
+                .map(|_| rng.range(1..=9) as u8)
+                .collect();
+            (battery, n)
+        });
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day3);
@@ -0,0 +1,126 @@
+use aoc_core::{AocError, Grid, Input, Rng, Solution};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum Cell {
+    Paper,
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub struct Floor {
+    data: Grid<Cell>,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = AocError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '@' => Ok(Cell::Paper),
+            '.' => Ok(Cell::None),
+            _ => Err(AocError::expected("`@` or `.`")),
+        }
+    }
+}
+
+impl Floor {
+    fn compute_convolution(&self) -> Grid<u8> {
+        self.data.map(|(x, y), c| {
+            if *c == Cell::Paper {
+                [
+                    (1, 0),
+                    (-1, 0),
+                    (0, 1),
+                    (0, -1),
+                    (-1, 1),
+                    (1, -1),
+                    (1, 1),
+                    (-1, -1),
+                ]
+                .iter()
+                .filter_map(|(i, j)| self.data.get_offset(x, y, *i, *j))
+                .filter(|c| **c == Cell::Paper)
+                .count() as u8
+            } else {
+                8
+            }
+        })
+    }
+
+    fn do_remove(&self, conv: Grid<u8>) -> Self {
+        Self {
+            data: self.data.map(|(x, y), cell| {
+                if *conv.get(x, y).unwrap() < 4 {
+                    Cell::None
+                } else {
+                    *cell
+                }
+            }),
+        }
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Parsed = Floor;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        Ok(Floor {
+            data: input.grid()?,
+        })
+    }
+
+    fn part1(floor: &Self::Parsed) -> Self::Part1 {
+        floor
+            .compute_convolution()
+            .iter()
+            .filter(|(_, cell)| **cell < 4)
+            .count()
+    }
+
+    fn part2(floor: &Self::Parsed) -> Self::Part2 {
+        let mut floor = floor.clone();
+        let mut count = 0;
+        let mut should_continue = true;
+        while should_continue {
+            let conv = floor.compute_convolution();
+            let res: usize = conv.iter().filter(|(_, cell)| **cell < 4).count();
+            count += res;
+            should_continue = res > 0;
+            floor = floor.do_remove(conv);
+        }
+
+        count
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let rows: Vec<String> = (0..140 * scale)
+            .map(|_| {
+                let row: String = (0..140)
+                    .map(|_| if rng.chance(0.6) { '@' } else { '.' })
+                    .collect();
+                format!("{}\n", row)
+            })
+            .collect();
+        Some(rows.concat())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_add() {
+        assert_eq!(5usize.checked_add_signed(1isize), Some(6));
+        assert_eq!(5usize.checked_add_signed(-1isize), Some(4));
+        assert_eq!(0usize.checked_add_signed(-1isize), None);
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day4);
@@ -0,0 +1,137 @@
+use std::ops::Range;
+
+use aoc_core::{AocError, Input, Rng, Solution, error::number};
+use tracing::debug;
+
+#[derive(Debug)]
+pub struct Inventory {
+    ranges: Vec<Range<usize>>,
+    items: Vec<usize>,
+}
+
+fn merge_pass(ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    let mut merged = vec![];
+    for range in ranges {
+        match (
+            get_merge_range(&range.start, &merged),
+            get_merge_range(&range.end, &merged),
+        ) {
+            (None, None) => merged.push(range),
+            (Some(a), Some(b)) => {
+                if a != b {
+                    merged.get_mut(a).unwrap().end = merged.remove(b).end
+                }
+            }
+            (Some(a), None) => merged.get_mut(a).unwrap().end = range.end,
+            (None, Some(b)) => merged.get_mut(b).unwrap().start = range.start,
+        }
+    }
+    merged
+}
+
+fn merge_ranges(ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+    let mut ranges = ranges.to_vec();
+
+    ranges.sort_by_key(|a| a.start);
+    let mut merged = merge_pass(ranges);
+
+    merged.sort_by_key(|a| a.end);
+    merge_pass(merged)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Parsed = Inventory;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        let mut sections = input.paragraphs();
+        let (Some(top), Some(bottom)) = (sections.next(), sections.next()) else {
+            return Err(AocError::after(
+                input,
+                "the ranges, a blank line, then the ingredients",
+            ));
+        };
+
+        let ranges = top
+            .split_pairs("-")
+            .map(|pair| {
+                let (start, end_text) = pair?;
+                let start: usize = number(start)?;
+                let end: usize = number(end_text)?;
+                // NOTE: the ranges are inclusive, so the end has to leave room for one past it
+                let end = end
+                    .checked_add(1)
+                    .ok_or_else(|| AocError::new(end_text, "an end below usize::MAX"))?;
+                Ok(start..end)
+            })
+            .collect::<Result<_, AocError>>()?;
+
+        let items = bottom.ints()?;
+
+        Ok(Inventory { ranges, items })
+    }
+
+    fn part1(inventory: &Self::Parsed) -> Self::Part1 {
+        p1(&inventory.items, &inventory.ranges)
+    }
+
+    fn part2(inventory: &Self::Parsed) -> Self::Part2 {
+        let merged = merge_ranges(&inventory.ranges);
+
+        debug!(?merged);
+
+        merged.into_iter().map(|r| r.count()).sum()
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        const MAX: i64 = 500_000_000_000_000;
+        let ranges: Vec<String> = (0..180 * scale)
+            .map(|_| {
+                let start = rng.range(1..=MAX);
+                format!("{}-{}\n", start, start + rng.range(0..=MAX / 100))
+            })
+            .collect();
+        let items: Vec<String> = (0..1000 * scale)
+            .map(|_| format!("{}\n", rng.range(1..=MAX)))
+            .collect();
+        Some(format!("{}\n{}", ranges.concat(), items.concat()))
+    }
+}
+
+fn p1(items: &[usize], ranges: &[Range<usize>]) -> usize {
+    items.iter().filter(|i| check_item(i, ranges)).count()
+}
+
+fn check_item(item: &usize, ranges: &[Range<usize>]) -> bool {
+    ranges.iter().filter(|range| range.contains(item)).count() > 0
+}
+
+fn get_merge_range(item: &usize, ranges: &[Range<usize>]) -> Option<usize> {
+    ranges
+        .iter()
+        .enumerate()
+        .find(|(_, range)| range.contains(item))
+        .map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_range_ending_at_max() {
+        let text = "1-18446744073709551615\n\n1";
+        let err = Day5::parse(&Input::new(text)).unwrap_err().locate(text);
+        assert_eq!(err.text, "18446744073709551615");
+        assert_eq!(err.location.unwrap().column, 3);
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day5);
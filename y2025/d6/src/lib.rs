@@ -0,0 +1,270 @@
+use aoc_core::{AocError, Assumption, Input, Rng, Solution, error::number};
+use tracing::trace;
+
+#[derive(Debug, PartialEq, Eq, Default, Clone, Copy)]
+enum Operators {
+    Plus,
+    Multiply,
+    #[default]
+    None,
+}
+
+impl TryFrom<char> for Operators {
+    type Error = AocError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '+' => Ok(Self::Plus),
+            '*' => Ok(Self::Multiply),
+            ' ' => Ok(Self::None),
+            _ => Err(AocError::expected("`+`, `*` or a space")),
+        }
+    }
+}
+
+impl Operators {
+    fn apply(&self, a: u64, b: u64) -> u64 {
+        match self {
+            Self::Plus => a + b,
+            Self::Multiply => {
+                if a == 0 {
+                    b
+                } else {
+                    a * b
+                }
+            }
+            Self::None => unreachable!("parse checks every problem starts with an operator"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct FoldState {
+    problem_op: Operators,
+    problem_total: u64,
+    total: u64,
+}
+
+#[derive(Debug)]
+pub struct Worksheet {
+    /// The lines of numbers, kept as text since part 2 reads them by column.
+    rows: Vec<String>,
+    /// The numbers in each line, one for every operator.
+    numbers: Vec<Vec<u64>>,
+    /// The operator under each column, `None` where there's a space.
+    ops: Vec<Operators>,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+
+    type Parsed = Worksheet;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        let mut lines: Vec<&str> = input.lines().collect();
+        let ops_line = lines
+            .pop()
+            .ok_or_else(|| AocError::after(input, "a line of operators"))?;
+
+        let ops: Vec<Operators> = ops_line
+            .char_indices()
+            .map(|(i, c)| {
+                Operators::try_from(c).map_err(|err| err.at(&ops_line[i..i + c.len_utf8()]))
+            })
+            .collect::<Result<_, _>>()?;
+        let problems = ops.iter().filter(|op| **op != Operators::None).count();
+
+        let mut numbers = Vec::with_capacity(lines.len());
+        for line in &lines {
+            if let Some((i, c)) = line
+                .char_indices()
+                .find(|(_, c)| !c.is_ascii_digit() && *c != ' ')
+            {
+                return Err(AocError::new(
+                    &line[i..i + c.len_utf8()],
+                    "a digit or a space",
+                ));
+            }
+            let row: Vec<&str> = line.split_whitespace().collect();
+            if let Some(extra) = row.get(problems) {
+                return Err(AocError::new(extra, "no more numbers than operators"));
+            }
+            if row.len() < problems {
+                return Err(AocError::after(
+                    line,
+                    format!("a number for each of the {} operators", problems),
+                ));
+            }
+            numbers.push(row.into_iter().map(number).collect::<Result<_, _>>()?);
+        }
+
+        // NOTE: part 2 reads each column as a number, and a problem starts at
+        // the first column after a gap, which needs an operator under it
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let mut in_problem = false;
+        for i in 0..width {
+            let digits = lines
+                .iter()
+                .filter_map(|line| line.as_bytes().get(i))
+                .any(|c| c.is_ascii_digit() && *c != b'0');
+            let op = ops.get(i).copied().unwrap_or_default();
+            if !digits && op == Operators::None {
+                in_problem = false;
+            } else if !in_problem && op == Operators::None {
+                let expected = "an operator at the start of the problem";
+                return Err(match ops_line.get(i..i + 1) {
+                    Some(space) => AocError::new(space, expected),
+                    None => AocError::after(ops_line, expected),
+                });
+            } else {
+                in_problem = true;
+            }
+        }
+
+        Ok(Worksheet {
+            rows: lines.into_iter().map(String::from).collect(),
+            numbers,
+            ops,
+        })
+    }
+
+    fn part1(worksheet: &Self::Parsed) -> Self::Part1 {
+        let ops = worksheet.ops.iter().filter(|op| **op != Operators::None);
+
+        ops.enumerate()
+            .map(|(i, op)| {
+                worksheet
+                    .numbers
+                    .iter()
+                    .fold(0, |acc, row| op.apply(acc, row[i]))
+            })
+            .sum()
+    }
+
+    fn part2(worksheet: &Self::Parsed) -> Self::Part2 {
+        let num_lines: Vec<Vec<char>> = worksheet
+            .rows
+            .iter()
+            .map(|line| line.chars().collect())
+            .collect();
+        let width = num_lines.iter().map(|line| line.len()).max().unwrap_or(0);
+
+        let res = (0..width.max(worksheet.ops.len()))
+            .map(|i| {
+                let n = num_lines
+                    .iter()
+                    .filter_map(|line| line.get(i))
+                    .filter_map(|c| u64::from_str_radix(&c.to_string(), 10).ok())
+                    .fold(0, |acc, v| acc * 10 + v);
+
+                let op = worksheet.ops.get(i).copied().unwrap_or_default();
+                if n == 0 && op == Operators::None {
+                    None
+                } else {
+                    Some((n, op))
+                }
+            })
+            .fold(FoldState::default(), |state, value| {
+                trace!(?state, ?value);
+                match value {
+                    None => FoldState {
+                        total: state.total + state.problem_total,
+                        problem_total: 0,
+                        problem_op: Operators::default(),
+                    },
+                    Some((n, op)) => {
+                        if op == Operators::None {
+                            FoldState {
+                                problem_op: state.problem_op,
+                                problem_total: state.problem_op.apply(state.problem_total, n),
+                                total: state.total,
+                            }
+                        } else {
+                            FoldState {
+                                problem_op: op,
+                                problem_total: op.apply(state.problem_total, n),
+                                total: state.total,
+                            }
+                        }
+                    }
+                }
+            });
+
+        // NOTE: the last problem isn't followed by a blank column, so add it on here
+        res.total + res.problem_total
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Parsed>> {
+        // NOTE: part 2 reads a number down each column, which fits in a u64
+        // with plenty to spare at the four rows the real input has
+        vec![Assumption::new(
+            "there are at most four rows of numbers",
+            |worksheet| worksheet.rows.len() <= 4,
+        )]
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let mut rows = vec![String::new(); 4];
+        let mut ops = String::new();
+        for problem in 0..1000 * scale {
+            if problem > 0 {
+                rows.iter_mut().for_each(|row| row.push(' '));
+                ops.push(' ');
+            }
+            // NOTE: one number always fills the problem and none has a 0, as part 2
+            // takes a column that reads as 0 to be the gap between problems
+            let width = rng.range(1..=3) as usize;
+            let full = rng.index(rows.len());
+            let left = rng.chance(0.5);
+            for (i, row) in rows.iter_mut().enumerate() {
+                let digits = if i == full {
+                    width
+                } else {
+                    rng.range(1..=width as i64) as usize
+                };
+                let n: String = (0..digits)
+                    .map(|_| char::from_digit(rng.range(1..=9) as u32, 10).unwrap())
+                    .collect();
+                match left {
+                    true => row.push_str(&format!("{:<width$}", n, width = width)),
+                    false => row.push_str(&format!("{:>width$}", n, width = width)),
+                }
+            }
+            ops.push(*rng.pick(&['+', '*']));
+            ops.push_str(&" ".repeat(width - 1));
+        }
+        rows.push(ops);
+        Some(rows.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(text: &str) -> AocError {
+        let input = Input::new(text);
+        Day6::parse(&input).unwrap_err().locate(text)
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed() {
+        let err = parse_error("1234567890123456789012345 1\n*                         +");
+        assert_eq!(err.text, "1234567890123456789012345");
+
+        let err = parse_error("12 3\n4\n*  +");
+        assert_eq!(err.location.unwrap().line, 2);
+
+        let err = parse_error("12 3\n 4 5\n *  +");
+        assert_eq!(err.text, " ");
+        assert_eq!(err.location.unwrap().column, 1);
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day6);
@@ -0,0 +1,235 @@
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_core::{AocError, Assumption, Grid, Input, Rng, Solution};
+use tracing::trace;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Cell {
+    Empty,
+    Splitter,
+    Beam,
+    Start,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Empty => ".",
+                Self::Splitter => "^",
+                Self::Beam => "|",
+                Self::Start => "S",
+            }
+        )
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = AocError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'S' => Ok(Self::Start),
+            '.' => Ok(Self::Empty),
+            '^' => Ok(Self::Splitter),
+            '|' => Ok(Self::Beam),
+            _ => Err(AocError::expected("one of `S`, `.`, `^` or `|`")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    cells: Grid<Cell>,
+    splits: usize,
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+impl State {
+    fn get(&self, x: usize, y: usize) -> Option<Cell> {
+        self.cells.get(x, y).copied()
+    }
+
+    fn update(&mut self, x: usize, y: usize, value: Cell) {
+        if let Some(cell) = self.cells.get_mut(x, y) {
+            *cell = value
+        }
+    }
+
+    fn extend_beam(&mut self, x: usize, y: usize) {
+        if self.get(x, y) == Some(Cell::Empty) {
+            self.update(x, y, Cell::Beam);
+            if self.get(x, y + 1) == Some(Cell::Empty) {
+                self.update(x, y + 1, Cell::Beam)
+            }
+        }
+    }
+
+    fn do_updates(&mut self, x: usize, y: usize) {
+        match self.get(x, y) {
+            None | Some(Cell::Empty) | Some(Cell::Splitter) => {}
+            Some(Cell::Start) => match self.get(x, y + 1) {
+                None => {}
+                Some(other) => match other {
+                    Cell::Empty => self.update(x, y + 1, Cell::Beam),
+                    Cell::Start => panic!("start should only be at the top of the layout"),
+                    Cell::Beam => {}
+                    Cell::Splitter => {}
+                },
+            },
+            Some(Cell::Beam) => match self.get(x, y + 1) {
+                None => {}
+                Some(other) => match other {
+                    Cell::Empty => self.extend_beam(x, y + 1),
+                    Cell::Splitter => {
+                        self.splits += 1;
+                        self.extend_beam(x - 1, y + 1);
+                        self.extend_beam(x + 1, y + 1);
+                    }
+                    Cell::Start => panic!("start should only be at the top of the layout"),
+                    Cell::Beam => {}
+                },
+            },
+        }
+    }
+}
+
+fn update_memo(
+    memo: &mut HashMap<(usize, usize), usize>,
+    depth: usize,
+    location: usize,
+    value: usize,
+) {
+    memo.entry((depth, location)).or_insert(value);
+}
+
+fn go_down(
+    memo: &mut HashMap<(usize, usize), usize>,
+    manifold: &Grid<Cell>,
+    depth: usize,
+    location: usize,
+) -> usize {
+    match memo.get(&(depth, location)) {
+        Some(value) => *value,
+        None => {
+            let value = match manifold.get(location, depth + 1) {
+                None => 1,
+                Some(cell) => match cell {
+                    Cell::Empty => go_down(memo, manifold, depth + 1, location),
+                    Cell::Splitter => {
+                        go_down(memo, manifold, depth + 1, location - 1)
+                            + go_down(memo, manifold, depth + 1, location + 1)
+                    }
+                    Cell::Beam | Cell::Start => 1,
+                },
+            };
+            update_memo(memo, depth, location, value);
+            value
+        }
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+
+    type Parsed = Grid<Cell>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        input.grid()
+    }
+
+    fn part1(manifold: &Self::Parsed) -> Self::Part1 {
+        let mut layout: State = State {
+            cells: manifold.clone(),
+            splits: 0,
+        };
+
+        let mut prev_splits = 0;
+        for y in 0..layout.cells.height() - 1 {
+            for x in 0..layout.cells.width() {
+                layout.do_updates(x, y);
+                if layout.splits != prev_splits {
+                    trace!(x, y, splits = layout.splits, "split\n{}", layout);
+                    prev_splits = layout.splits;
+                }
+            }
+        }
+
+        layout.splits
+    }
+
+    fn part2(manifold: &Self::Parsed) -> Self::Part2 {
+        go_down(
+            &mut HashMap::new(),
+            manifold,
+            0,
+            manifold
+                .rows()
+                .next()
+                .unwrap()
+                .iter()
+                .position(|c| *c == Cell::Start)
+                .unwrap(),
+        )
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Parsed>> {
+        vec![
+            Assumption::new("there is one S, on the top row", |manifold| {
+                let mut rows = manifold.rows();
+                rows.next()
+                    .is_some_and(|top| top.iter().filter(|c| **c == Cell::Start).count() == 1)
+                    && rows.flatten().all(|c| *c != Cell::Start)
+            }),
+            Assumption::new("there's no splitter on an edge column", |manifold| {
+                manifold.rows().all(|row| {
+                    row.first() != Some(&Cell::Splitter) && row.last() != Some(&Cell::Splitter)
+                })
+            }),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let width = 141;
+        let mut rows = vec![format!(
+            "{}S{}",
+            ".".repeat(width / 2),
+            ".".repeat(width / 2)
+        )];
+        // NOTE: each row of splitters multiplies the timelines by about 1 + density, so
+        // taller grids get sparser splitters to keep part 2 from overflowing
+        let density = 1.6f64.powf(1.0 / scale as f64) - 1.0;
+        for y in 1..142 * scale {
+            // NOTE: splitters sit on every other cell of every other row, staggered like
+            // the real ones, so no two touch, and off the edges so split beams stay on
+            let row: String = (0..width)
+                .map(|x| {
+                    let spot = y % 2 == 0 && (x + y / 2) % 2 == (width / 2 + 1) % 2;
+                    let inside = x > 0 && x < width - 1;
+                    if spot && inside && rng.chance(density) {
+                        '^'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            rows.push(row);
+        }
+        Some(rows.join("\n"))
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day7);
@@ -0,0 +1,191 @@
+use std::collections::HashSet;
+
+use aoc_core::{AocError, Assumption, Input, Rng, Solution};
+use tracing::debug;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
+pub struct Point {
+    x: u64,
+    y: u64,
+    z: u64,
+}
+
+impl Point {
+    fn distance_square(&self, other: &Self) -> u64 {
+        vec![
+            self.x.abs_diff(other.x),
+            self.y.abs_diff(other.y),
+            self.z.abs_diff(other.z),
+        ]
+        .into_iter()
+        .map(|v| v * v)
+        .sum()
+    }
+}
+
+fn sorted_pairs(points: &[Point]) -> Vec<((Point, Point), u64)> {
+    let pairs: Vec<(Point, Point)> = combinatorial::Combinations::of_size(points.to_vec(), 2)
+        .map(|i| {
+            let mut i = i.into_iter();
+            (i.next().unwrap(), i.next().unwrap())
+        })
+        .collect();
+
+    let mut pair_distance: Vec<((Point, Point), u64)> = pairs
+        .into_iter()
+        .map(|(a, b)| {
+            let dist = a.distance_square(&b);
+            ((a, b), dist)
+        })
+        .collect();
+    pair_distance.sort_by_key(|((_, _), n)| *n);
+
+    pair_distance
+}
+
+fn connect(circuits: &mut Vec<HashSet<Point>>, pair: &(Point, Point)) {
+    let mut found: Vec<(usize, &mut HashSet<Point>)> = circuits
+        .iter_mut()
+        .enumerate()
+        .filter(|(_, c)| c.contains(&pair.0) || c.contains(&pair.1))
+        .collect();
+
+    let remove = match found.len() {
+        0 => {
+            circuits.push(HashSet::from([pair.0, pair.1]));
+            None
+        }
+        // NOTE: this will only add one of the two points as one is already contained
+        1 => {
+            found.first_mut().unwrap().1.extend([pair.0, pair.1]);
+            None
+        }
+        2 => {
+            let update = found.remove(1);
+            found.first_mut().unwrap().1.extend(update.1.to_owned());
+            Some(update.0)
+        }
+        i => unreachable!("how is it {}", i),
+    };
+    match remove {
+        None => {}
+        Some(remove) => {
+            circuits.swap_remove(remove);
+        }
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    type Parsed = Vec<Point>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, AocError> {
+        let ints = input.ints()?;
+        if !ints.len().is_multiple_of(3) {
+            return Err(AocError::after(input, "three coordinates for the last box"));
+        }
+
+        Ok(ints
+            .chunks_exact(3)
+            .map(|nums| Point {
+                x: nums[0],
+                y: nums[1],
+                z: nums[2],
+            })
+            .collect())
+    }
+
+    fn part1(points: &Self::Parsed) -> Self::Part1 {
+        let mut circuits: Vec<HashSet<Point>> = vec![];
+
+        for (pair, _) in sorted_pairs(points).iter().take(1000) {
+            connect(&mut circuits, pair);
+        }
+
+        let mut sizes: Vec<usize> = circuits.into_iter().map(|c| c.len()).collect();
+        sizes.sort_by(|a, b| b.cmp(a));
+
+        debug!(?sizes);
+
+        sizes.iter().take(3).product::<usize>()
+    }
+
+    fn part2(points: &Self::Parsed) -> Self::Part2 {
+        let mut circuits: Vec<HashSet<Point>> = vec![];
+
+        for (pair, _) in sorted_pairs(points).iter() {
+            connect(&mut circuits, pair);
+            if circuits.len() == 1 && circuits.first().unwrap().len() == points.len() {
+                debug!(?pair, "last connection");
+                return pair.0.x * pair.1.x;
+            }
+        }
+
+        unreachable!("the points never form a single circuit")
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Parsed>> {
+        // NOTE: part 1 connects the 1000 closest pairs, which the example scales down
+        vec![
+            Assumption::new("there are at least 1000 junction boxes", |points| {
+                points.len() >= 1000
+            }),
+            // NOTE: two boxes in the same spot are already joined, so the last connection
+            // never comes
+            Assumption::new("the junction boxes are distinct", |points| {
+                points.iter().collect::<HashSet<_>>().len() == points.len()
+            }),
+            // NOTE: then three squared differences, and part 2's product, fit in a u64
+            Assumption::new("every coordinate is below 2^31", |points| {
+                points
+                    .iter()
+                    .all(|p| [p.x, p.y, p.z].iter().all(|c| *c < 1 << 31))
+            }),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let boxes: Vec<String> = (0..1000 * scale)
+            .map(|_| {
+                let [x, y, z] = [(); 3].map(|_| rng.range(0..=99_999));
+                format!("{},{},{}\n", x, y, z)
+            })
+            .collect();
+        Some(boxes.concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn broken(points: &Vec<Point>) -> Vec<&'static str> {
+        Day8::assumptions()
+            .into_iter()
+            .filter(|assumption| !(assumption.holds)(points))
+            .map(|assumption| assumption.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_assumptions() {
+        let mut points: Vec<Point> = (0..1000).map(|x| Point { x, y: 0, z: 0 }).collect();
+        assert!(broken(&points).is_empty());
+
+        points.push(points[0]);
+        assert_eq!(broken(&points), ["the junction boxes are distinct"]);
+
+        points.pop();
+        points[0].z = 1 << 31;
+        assert_eq!(broken(&points), ["every coordinate is below 2^31"]);
+    }
+}
+
+#[cfg(test)]
+aoc_macros::example_tests!(Day8);